@@ -4,7 +4,7 @@ use gemini_engine::{
     core::{ColChar, Modifier, Vec2D},
     fps_gameloop,
     primitives::{Line, Pixel, Rect},
-    view::{View, WrappingMode},
+    view::{FrameStats, View, WrappingMode},
 };
 use std::time::Duration;
 
@@ -37,8 +37,7 @@ fn main() {
         Modifier::from_rgb(20, 200, 0),
     );
 
-    let mut draw_elapsed = Duration::default();
-    let mut render_elapsed = Duration::default();
+    let mut stats = FrameStats::default();
     fps_gameloop!(
         {
             pixel.pos.x += 2;
@@ -56,29 +55,23 @@ fn main() {
             sprite.pos.x += 1;
         },
         {
-            view.clear();
+            stats.time_draw(|| {
+                view.clear();
 
-            let now = Instant::now();
-            view.wrapping_mode = WrappingMode::Panic;
-            view.draw(&pixel);
-            view.draw(&line);
-            view.draw(&rect);
-            view.wrapping_mode = WrappingMode::Wrap;
-            view.draw(&sprite);
-            draw_elapsed = now.elapsed();
+                view.wrapping_mode = WrappingMode::Panic;
+                view.draw(&pixel);
+                view.draw(&line);
+                view.draw(&rect);
+                view.wrapping_mode = WrappingMode::Wrap;
+                view.draw(&sprite);
+            });
+            view.draw(&stats.overlay(Vec2D::new(22, 6)));
 
-            let now = Instant::now();
-            let _ = view.display_render();
-            render_elapsed = now.elapsed();
+            let _ = stats.display_render(&mut view);
         },
         FPS,
-        |total_elapsed: Duration, _frame_skip| {
-            println!(
-                "Drawing: {:.2?} microseconds | Rendering: {:.2?} microseconds| Total: {:.2?}",
-                draw_elapsed.as_micros(),
-                render_elapsed.as_micros(),
-                total_elapsed.as_micros()
-            );
+        |total_elapsed: Duration, frame_skip: bool| {
+            stats.end_frame(total_elapsed, frame_skip);
             println!("Pixel position: {}", pixel.pos);
         }
     );
@@ -3,7 +3,7 @@ use gemini_engine::{
     core::ColChar,
     mesh3d::{Mesh3D, Transform3D, Vec3D},
     view::View,
-    view3d::{DisplayMode, Light, Viewport},
+    view3d::{DisplayMode, Light, Shading, Viewport},
 };
 use std::{thread, time::Duration};
 
@@ -16,7 +16,8 @@ fn main() {
         Transform3D::look_at_lh(Vec3D::new(0.0, -3.0, 6.0), Vec3D::ZERO, Vec3D::Y),
         FOV,
         view.center(),
-    );
+    )
+    .with_shading(Shading::Gouraud);
 
     viewport.display_mode = DisplayMode::Illuminated {
         lights: vec![
@@ -0,0 +1,74 @@
+//! Move a pixel around with the arrow keys. Press `q` or `Ctrl+C` to quit
+
+use gemini_engine::{
+    core::{ColChar, Vec2D},
+    gameloop::MainLoopRoot,
+    input::{Input, Key, KeyEvent, KeyModifiers},
+    primitives::Pixel,
+    view::{SessionOptions, TerminalSession, View, WrappingMode},
+};
+
+struct Game {
+    session: Option<TerminalSession>,
+    input: Input,
+    view: View,
+    pixel: Pixel,
+}
+
+impl Game {
+    fn new() -> Self {
+        Self {
+            session: Some(
+                TerminalSession::new(SessionOptions::default().with_raw_mode())
+                    .expect("Failed to start terminal session"),
+            ),
+            input: Input::new().expect("Failed to start reading input"),
+            view: View::new(40, 8, ColChar::BACKGROUND).with_wrapping_mode(WrappingMode::Wrap),
+            pixel: Pixel::new(Vec2D::new(20, 4), ColChar::SOLID),
+        }
+    }
+}
+
+impl MainLoopRoot for Game {
+    fn get_fps(&self) -> f32 {
+        30.0
+    }
+
+    fn frame(&mut self) {
+        self.input.update();
+        let keyboard = &self.input.keyboard;
+
+        let directions = [
+            (Key::Up, Vec2D::new(0, -1)),
+            (Key::Down, Vec2D::new(0, 1)),
+            (Key::Left, Vec2D::new(-1, 0)),
+            (Key::Right, Vec2D::new(1, 0)),
+        ];
+        for (key, direction) in directions {
+            if keyboard.is_held(key) {
+                self.pixel.pos += direction;
+            }
+        }
+        self.pixel.pos = self.pixel.pos.rem_euclid(self.view.size());
+
+        if keyboard.is_pressed(Key::Char('q'))
+            || keyboard.is_pressed(KeyEvent::new(Key::Char('c'), KeyModifiers::CONTROL))
+        {
+            // `exit` doesn't run destructors, so restore the terminal first
+            drop(self.session.take());
+            std::process::exit(0);
+        }
+    }
+
+    fn render_frame(&mut self) {
+        self.view.clear();
+        self.view.draw(&self.pixel);
+        let _ = self.view.display_render();
+    }
+}
+
+fn main() {
+    let mut game = Game::new();
+
+    game.main_loop();
+}
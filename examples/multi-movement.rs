@@ -22,7 +22,7 @@ fn main() {
     loop {
         if blocks.iter().all(|b| b.pos.x % view.width as i64 == 0) {
             thread::sleep(Duration::from_secs(2));
-        };
+        }
 
         i += 1;
         for (j, block) in (0u32..).zip(blocks.iter_mut()) {
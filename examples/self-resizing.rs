@@ -30,7 +30,7 @@ fn main() {
         scale_view.update();
         scale_view.view.draw(&text);
         scale_view.view.draw(&sprite);
-        let _ = scale_view.view.display_render();
+        let _ = scale_view.display_render();
 
         thread::sleep(Duration::from_millis(10));
     }
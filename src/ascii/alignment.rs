@@ -1,4 +1,20 @@
-use crate::core::Vec2D;
+use crate::core::{Vec2D, char_width};
+
+/// Return the number of columns `text` takes up when drawn by a [`Text`](super::Text)
+///
+/// Wide characters such as `中` or `🦀` take up two columns, and combining characters such as accents take up none, as they are drawn on top of the character before them
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::ascii::display_width;
+/// assert_eq!(display_width("█▀▄"), 3);
+/// assert_eq!(display_width("中文"), 4);
+/// assert_eq!(display_width("e\u{301}"), 1);
+/// ```
+#[must_use]
+pub fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
 
 /// An enum to determine the alignment of an [ascii](super) element's content
 #[derive(Debug, Clone, Copy)]
@@ -9,7 +9,7 @@ pub struct AnimatedSprite {
     pub frames: Vec<String>,
     /// The current frame being displayed. This will index directly into [`frames`](AnimatedSprite::frames)
     current_frame: usize,
-    /// A raw [`Modifier`], determining the appearance of the `AnimatedSprite`
+    /// A raw [`Modifier`], determining the appearance of the `AnimatedSprite`. Use [`Modifier::Style`] for a background colour or text attributes
     pub modifier: Modifier,
     /// How the Sprite should align to the position
     pub align: TextAlign2D,
@@ -18,7 +18,7 @@ pub struct AnimatedSprite {
 impl AnimatedSprite {
     /// Create a new `AnimatedSprite` struct. All newlines at the beginning of each texture will be removed
     #[must_use]
-    pub fn new(pos: Vec2D, frames: &[&str], modifier: Modifier) -> Self {
+    pub fn new(pos: Vec2D, frames: &[&str], modifier: impl Into<Modifier>) -> Self {
         let processed_frames: Vec<String> = frames
             .iter()
             .map(|frame| frame.trim_start_matches('\n').into())
@@ -28,7 +28,7 @@ impl AnimatedSprite {
             pos,
             frames: processed_frames,
             current_frame: 0,
-            modifier,
+            modifier: modifier.into(),
             align: TextAlign2D::default(),
         }
     }
@@ -40,13 +40,13 @@ impl AnimatedSprite {
     }
 
     /// Sets the current frame
-    pub fn set_current_frame(&mut self, value: usize) {
+    pub const fn set_current_frame(&mut self, value: usize) {
         self.current_frame = value;
         self.current_frame = self.current_frame.rem_euclid(self.frames.len());
     }
 
     /// Go to the next frame of the `AnimatedSprite`'s frames. Will automatically wrap around at the end of the list
-    pub fn next_frame(&mut self) {
+    pub const fn next_frame(&mut self) {
         self.current_frame += 1;
         if self.current_frame >= self.frames.len() {
             self.current_frame = 0;
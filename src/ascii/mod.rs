@@ -10,4 +10,4 @@ mod text;
 pub use text::Text;
 
 mod alignment;
-pub use alignment::{TextAlign, TextAlign2D};
+pub use alignment::{TextAlign, TextAlign2D, display_width};
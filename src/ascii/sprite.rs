@@ -1,5 +1,8 @@
-use super::{Text, TextAlign2D};
-use crate::core::{CanDraw, Modifier, Vec2D};
+use super::{Text, TextAlign2D, display_width, text};
+use crate::{
+    containers::CanCollide,
+    core::{CanDraw, Modifier, Vec2D},
+};
 
 /// The `Sprite` takes a multi-line string as a parameter, and can be used to draw ASCII art to a `Canvas`
 #[non_exhaustive]
@@ -9,7 +12,7 @@ pub struct Sprite {
     pub pos: Vec2D,
     /// The ACII texture (pun intended) displayed by the `Sprite`
     pub texture: String,
-    /// A raw [`Modifier`], determining the appearance of the `Sprite`
+    /// A raw [`Modifier`], determining the appearance of the `Sprite`. Use [`Modifier::Style`] for a background colour or text attributes
     pub modifier: Modifier,
     /// How the Sprite should align to the position
     pub align: TextAlign2D,
@@ -18,11 +21,11 @@ pub struct Sprite {
 impl Sprite {
     /// Create a new `Sprite` struct. All newlines at the beginning of the texture will be removed
     #[must_use]
-    pub fn new(pos: Vec2D, texture: &str, modifier: Modifier) -> Self {
+    pub fn new(pos: Vec2D, texture: &str, modifier: impl Into<Modifier>) -> Self {
         Self {
             pos,
             texture: texture.trim_start_matches('\n').into(),
-            modifier,
+            modifier: modifier.into(),
             align: TextAlign2D::default(),
         }
     }
@@ -33,15 +36,20 @@ impl Sprite {
         self.align = align;
         self
     }
-}
 
-impl CanDraw for Sprite {
-    fn draw_to(&self, canvas: &mut impl crate::core::Canvas) {
+    /// Return the position of the top left corner of the `Sprite` once aligned
+    fn top_left(&self) -> Vec2D {
         let content_size = Vec2D::new(
-            self.texture.lines().map(str::len).max().unwrap_or(0) as i64,
+            self.texture.lines().map(display_width).max().unwrap_or(0) as i64,
             self.texture.lines().count() as i64,
         );
-        let pos = self.align.apply_to(self.pos, content_size);
+        self.align.apply_to(self.pos, content_size)
+    }
+}
+
+impl CanDraw for Sprite {
+    fn draw_to(&self, canvas: &mut impl crate::core::Canvas) {
+        let pos = self.top_left();
 
         let lines = self.texture.split('\n');
         for (y, line) in (0..).zip(lines) {
@@ -49,3 +57,14 @@ impl CanDraw for Sprite {
         }
     }
 }
+
+impl CanCollide for Sprite {
+    /// Returns `true` if the `Sprite` draws a character at the passed position. Spaces are not drawn, and so do not collide
+    fn collides_with_pos(&self, pos: Vec2D) -> bool {
+        let top_left = self.top_left();
+        usize::try_from(pos.y - top_left.y)
+            .ok()
+            .and_then(|y| self.texture.split('\n').nth(y))
+            .is_some_and(|line| text::line_covers_column(line, top_left.x, pos.x))
+    }
+}
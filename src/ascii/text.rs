@@ -1,8 +1,26 @@
-use crate::core::{CanDraw, ColChar, Modifier, Vec2D};
+use crate::{
+    containers::CanCollide,
+    core::{CanDraw, ColChar, Modifier, Vec2D, char_width},
+};
+use std::iter;
 
-use super::TextAlign;
+use super::{TextAlign, display_width};
 
 /// Displays text at the given position
+///
+/// Text is laid out by display width, as a terminal would. Wide characters such as `中` or `🦀` take up two cells, the second being a [`ColChar::CONTINUATION`], and combining characters such as accents are attached to the character before them as its [`combining`](ColChar::combining) character
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::{ascii::Text, view::View, core::{ColChar, Modifier, Vec2D}};
+/// let mut view = View::new(6, 1, ColChar::EMPTY);
+/// view.draw(&Text::new(Vec2D::ZERO, "中e\u{301}!", Modifier::None));
+///
+/// assert_eq!(view.get(Vec2D::new(1, 0)), Some(ColChar::CONTINUATION));
+/// assert_eq!(view.get(Vec2D::new(2, 0)), Some(ColChar::new('e', Modifier::None).with_combining('\u{301}')));
+/// assert_eq!(view.get(Vec2D::new(3, 0)), Some(ColChar::new('!', Modifier::None)));
+/// assert_eq!(view.to_plain_text(), "中e\u{301}!  \n");
+/// ```
 #[non_exhaustive]
 #[derive(Debug, Clone)]
 pub struct Text {
@@ -12,7 +30,7 @@ pub struct Text {
     pub content: String,
     /// How the content should align to the `pos` property
     pub align: TextAlign,
-    /// A raw [`Modifier`], determining the appearance of the `Text`
+    /// A raw [`Modifier`], determining the appearance of the `Text`. Use [`Modifier::Style`] for a background colour or text attributes
     pub modifier: Modifier,
 }
 
@@ -22,7 +40,7 @@ impl Text {
     /// # Panics
     /// This function will panic if the content contains a newline, as Text only works with single lines. For multi-line strings, see [Sprite](super::Sprite)
     #[must_use]
-    pub fn new(pos: Vec2D, content: &str, modifier: Modifier) -> Self {
+    pub fn new(pos: Vec2D, content: &str, modifier: impl Into<Modifier>) -> Self {
         assert!(
             !content.contains('\n'),
             "Text was created with a content string containing a \n character"
@@ -32,7 +50,7 @@ impl Text {
             pos,
             content: String::from(content),
             align: TextAlign::Begin,
-            modifier,
+            modifier: modifier.into(),
         }
     }
 
@@ -42,20 +60,81 @@ impl Text {
         self.align = align;
         self
     }
+
+    /// Return the x position that the `Text` starts at once aligned
+    fn start_x(&self) -> i64 {
+        self.align
+            .apply_to(self.pos.x, display_width(&self.content) as i64)
+    }
 }
 
-impl CanDraw for Text {
-    fn draw_to(&self, canvas: &mut impl crate::core::Canvas) {
-        let mut pos = self.pos;
-        pos.x = self.align.apply_to(pos.x, self.content.len() as i64);
+/// Return the x position, [`ColChar`] and width of every character drawn for a line of text starting at `x`. Spaces are skipped, as they aren't drawn
+pub(super) fn layout_line(
+    line: &str,
+    mut x: i64,
+    modifier: Modifier,
+) -> impl Iterator<Item = (i64, ColChar, i64)> + '_ {
+    let mut chars = line.chars().peekable();
+    iter::from_fn(move || {
+        loop {
+            let text_char = chars.next()?;
+            let width = char_width(text_char) as i64;
+            // A combining character with nothing before it to attach to
+            if width == 0 {
+                continue;
+            }
 
-        for (x, text_char) in (0..).zip(self.content.chars()) {
+            let mut c = ColChar::new(text_char, modifier);
+            c.combining = chars.next_if(|c| char_width(*c) == 0);
+            while chars.next_if(|c| char_width(*c) == 0).is_some() {}
+
+            let char_x = x;
+            x += width;
             if text_char != ' ' {
+                return Some((char_x, c, width));
+            }
+        }
+    })
+}
+
+/// Return `true` if a line of text starting at `x` draws a character in the column `column`
+pub(super) fn line_covers_column(line: &str, x: i64, column: i64) -> bool {
+    layout_line(line, x, Modifier::None)
+        .take_while(|(char_x, _, _)| *char_x <= column)
+        .any(|(char_x, _, width)| column < char_x + width)
+}
+
+impl CanDraw for Text {
+    fn draw_to(&self, canvas: &mut impl crate::core::Canvas) {
+        for (x, c, width) in layout_line(&self.content, self.start_x(), self.modifier) {
+            let pos = Vec2D::new(x, self.pos.y);
+            canvas.plot(pos, c);
+            if width == 2 {
                 canvas.plot(
-                    pos + Vec2D::new(x, 0),
-                    ColChar::new(text_char, self.modifier),
+                    pos + Vec2D::new(1, 0),
+                    ColChar::CONTINUATION.with_mod(self.modifier),
                 );
             }
         }
     }
 }
+
+impl CanCollide for Text {
+    /// Returns `true` if the `Text` draws a character at the passed position. Spaces are not drawn, and so do not collide
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::{ascii::{Text, TextAlign}, containers::CanCollide, core::{Modifier, Vec2D}};
+    /// let text = Text::new(Vec2D::new(10, 2), "中 a", Modifier::None).with_align(TextAlign::End);
+    ///
+    /// // Both cells of the wide character collide, but the space between doesn't
+    /// assert!(text.collides_with_pos(Vec2D::new(6, 2)));
+    /// assert!(text.collides_with_pos(Vec2D::new(7, 2)));
+    /// assert!(!text.collides_with_pos(Vec2D::new(8, 2)));
+    /// assert!(text.collides_with_pos(Vec2D::new(9, 2)));
+    /// assert!(!text.collides_with_pos(Vec2D::new(9, 1)));
+    /// ```
+    fn collides_with_pos(&self, pos: Vec2D) -> bool {
+        pos.y == self.pos.y && line_covers_column(&self.content, self.start_x(), pos.x)
+    }
+}
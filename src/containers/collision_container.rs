@@ -35,6 +35,26 @@ impl<'e> CollisionContainer<'e> {
         self.elements.push(element);
     }
 
+    /// Return the index in `elements` of the element that intersects the passed position, or `None` if none of them do. If several elements intersect it, the one that was pushed last is returned. Useful for finding out what was clicked on with a [`MouseEvent`](crate::input::MouseEvent)
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::{containers::CollisionContainer, core::{ColChar, Vec2D}, primitives::Rect};
+    /// let play_button = Rect::new(Vec2D::new(0, 0), Vec2D::new(6, 1), ColChar::SOLID);
+    /// let quit_button = Rect::new(Vec2D::new(0, 2), Vec2D::new(6, 1), ColChar::SOLID);
+    ///
+    /// let mut buttons = CollisionContainer::new();
+    /// buttons.push(&play_button);
+    /// buttons.push(&quit_button);
+    ///
+    /// assert_eq!(buttons.element_at(Vec2D::new(3, 2)), Some(1));
+    /// assert_eq!(buttons.element_at(Vec2D::new(3, 1)), None);
+    /// ```
+    #[must_use]
+    pub fn element_at(&self, pos: Vec2D) -> Option<usize> {
+        self.elements.iter().rposition(|e| e.collides_with_pos(pos))
+    }
+
     /// Returns true if the given element implementing [`CanDraw`] is overlapping the `CollisionContainer`
     pub fn overlaps_element(&self, element: &impl CanDraw) -> bool {
         self.will_overlap_element(element, Vec2D::ZERO)
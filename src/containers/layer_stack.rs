@@ -0,0 +1,123 @@
+use crate::{
+    core::{CanDraw, Canvas, ColChar, Vec2D},
+    view::View,
+};
+
+/// A single layer of a [`LayerStack`], made up of a [`View`] and where and whether it should be drawn
+#[derive(Debug, Clone)]
+pub struct Layer {
+    /// The `View` holding the layer's pixels. Pixels that are [`ColChar::TRANSPARENT`] will show the layers below
+    pub view: View,
+    /// Layers with a higher `z_index` are drawn over layers with a lower one. Layers with the same `z_index` are drawn in the order they were added
+    pub z_index: i64,
+    /// The position of the layer's top left corner on the canvas it is drawn to
+    pub offset: Vec2D,
+    /// Whether the layer is drawn at all
+    pub visible: bool,
+}
+
+impl Layer {
+    /// Create a new, visible `Layer` at `(0,0)` with a `z_index` of 0
+    #[must_use]
+    pub const fn new(view: View) -> Self {
+        Self {
+            view,
+            z_index: 0,
+            offset: Vec2D::ZERO,
+            visible: true,
+        }
+    }
+
+    /// Create a new `Layer` with a transparent `View` of the given size, ready to be drawn to
+    #[must_use]
+    pub fn transparent(width: usize, height: usize) -> Self {
+        Self::new(View::new(width, height, ColChar::TRANSPARENT))
+    }
+
+    /// Return the `Layer` with an updated `z_index` property. Consumes the original `Layer`
+    #[must_use]
+    pub const fn with_z_index(mut self, z_index: i64) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Return the `Layer` with an updated `offset` property. Consumes the original `Layer`
+    #[must_use]
+    pub const fn with_offset(mut self, offset: Vec2D) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl CanDraw for Layer {
+    fn draw_to(&self, canvas: &mut impl Canvas) {
+        struct OffsetCanvas<'c, C: Canvas>(&'c mut C, Vec2D);
+        impl<C: Canvas> Canvas for OffsetCanvas<'_, C> {
+            fn plot(&mut self, pos: Vec2D, c: ColChar) {
+                self.0.plot(pos + self.1, c);
+            }
+        }
+
+        if self.visible {
+            self.view.draw_to(&mut OffsetCanvas(canvas, self.offset));
+        }
+    }
+}
+
+/// A `LayerStack` holds several [`Layer`]s, and composites them into a single frame when drawn to a [`Canvas`]
+///
+/// Layers are drawn from the lowest `z_index` to the highest, so you can redraw a HUD every frame without redrawing the background beneath it
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::{containers::{Layer, LayerStack}, core::{ColChar, Vec2D}, primitives::Rect, view::View};
+/// let mut background = Layer::transparent(4, 1);
+/// background.view.draw(&Rect::new(Vec2D::ZERO, Vec2D::new(4, 1), ColChar::BACKGROUND));
+///
+/// let mut hud = Layer::transparent(1, 1).with_z_index(1).with_offset(Vec2D::new(2, 0));
+/// hud.view.draw(&Rect::new(Vec2D::ZERO, Vec2D::ONE, ColChar::SOLID));
+///
+/// // Layers are sorted by `z_index`, not the order they were added
+/// let stack = LayerStack::from(vec![hud, background]);
+///
+/// let mut view = View::new(4, 1, ColChar::EMPTY);
+/// view.draw(&stack);
+/// assert_eq!(view.to_plain_text(), "░░█░\n");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LayerStack {
+    /// The layers in the stack, in the order they were added
+    pub layers: Vec<Layer>,
+}
+
+impl LayerStack {
+    /// Create a new, empty `LayerStack`
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { layers: vec![] }
+    }
+
+    /// Add a layer to the stack, returning its index in [`layers`](LayerStack::layers)
+    pub fn push(&mut self, layer: Layer) -> usize {
+        self.layers.push(layer);
+        self.layers.len() - 1
+    }
+}
+
+impl From<Vec<Layer>> for LayerStack {
+    fn from(layers: Vec<Layer>) -> Self {
+        Self { layers }
+    }
+}
+
+impl CanDraw for LayerStack {
+    /// Draw every visible layer to the canvas, from the lowest `z_index` to the highest
+    fn draw_to(&self, canvas: &mut impl Canvas) {
+        let mut layers: Vec<&Layer> = self.layers.iter().collect();
+        layers.sort_by_key(|layer| layer.z_index);
+
+        for layer in layers {
+            layer.draw_to(canvas);
+        }
+    }
+}
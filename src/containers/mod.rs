@@ -11,3 +11,6 @@ pub use shader::CanShade;
 
 mod collision_container;
 pub use collision_container::{CanCollide, CollisionContainer};
+
+mod layer_stack;
+pub use layer_stack::{Layer, LayerStack};
@@ -20,12 +20,35 @@ pub struct Colour {
     pub b: u8,
 }
 
+/// The levels of each channel in the 6x6x6 colour cube of the 256 colour xterm palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
 impl Colour {
     /// A white `Colour` of RGB (0,0,0)
     pub const BLACK: Self = Self::greyscale(0);
     /// A white `Colour` of RGB (255,255,255)
     pub const WHITE: Self = Self::greyscale(255);
 
+    /// The colours of the 16 standard ANSI colour codes, as displayed by xterm. The first 8 are the normal colours (codes 30-37), followed by their bright versions (codes 90-97). Most terminals let the user change these, so they are only an approximation
+    pub const ANSI_PALETTE: [Self; 16] = [
+        Self::rgb(0, 0, 0),
+        Self::rgb(205, 0, 0),
+        Self::rgb(0, 205, 0),
+        Self::rgb(205, 205, 0),
+        Self::rgb(0, 0, 238),
+        Self::rgb(205, 0, 205),
+        Self::rgb(0, 205, 205),
+        Self::rgb(229, 229, 229),
+        Self::rgb(127, 127, 127),
+        Self::rgb(255, 0, 0),
+        Self::rgb(0, 255, 0),
+        Self::rgb(255, 255, 0),
+        Self::rgb(92, 92, 255),
+        Self::rgb(255, 0, 255),
+        Self::rgb(0, 255, 255),
+        Self::rgb(255, 255, 255),
+    ];
+
     /// Create a `Colour` from an RGB value
     #[must_use]
     pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
@@ -66,6 +89,100 @@ impl Colour {
     pub const fn greyscale(v: u8) -> Self {
         Self::rgb(v, v, v)
     }
+
+    /// Mix two colours together. An `opacity` of `0.0` returns `self`, `1.0` returns `other`, and anything in between is a linear mix of the two. `opacity` is clamped between `0.0` and `1.0`
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::core::Colour;
+    /// let grey = Colour::BLACK.blend(Colour::WHITE, 0.5);
+    /// assert_eq!(grey, Colour::greyscale(128));
+    /// ```
+    #[must_use]
+    pub fn blend(self, other: Self, opacity: f64) -> Self {
+        let opacity = opacity.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| f64::from(a).mul_add(1.0 - opacity, f64::from(b) * opacity);
+
+        Self::rgb(
+            mul_by_f64_to_u8(mix(self.r, other.r), 1.0),
+            mul_by_f64_to_u8(mix(self.g, other.g), 1.0),
+            mul_by_f64_to_u8(mix(self.b, other.b), 1.0),
+        )
+    }
+
+    /// Return the squared distance between two colours in RGB space
+    const fn distance_squared(self, other: Self) -> u32 {
+        let dr = self.r.abs_diff(other.r) as u32;
+        let dg = self.g.abs_diff(other.g) as u32;
+        let db = self.b.abs_diff(other.b) as u32;
+        dr * dr + dg * dg + db * db
+    }
+
+    /// Return the index of the nearest colour in [`Colour::ANSI_PALETTE`]
+    #[must_use]
+    pub fn to_ansi_16(self) -> u8 {
+        (0..)
+            .zip(Self::ANSI_PALETTE)
+            .min_by_key(|(_, c)| self.distance_squared(*c))
+            .map_or(0, |(i, _)| i)
+    }
+
+    /// Return the index of the nearest colour in the xterm 256 colour palette, only considering the 6x6x6 colour cube (16-231) and the greyscale ramp (232-255), as the first 16 colours are often changed by the user
+    #[must_use]
+    pub fn to_ansi_256(self) -> u8 {
+        let nearest_level = |v: u8| {
+            (0..)
+                .zip(CUBE_LEVELS)
+                .min_by_key(|(_, level)| v.abs_diff(*level))
+                .map_or(0, |(i, _)| i)
+        };
+
+        let (r, g, b) = (
+            nearest_level(self.r),
+            nearest_level(self.g),
+            nearest_level(self.b),
+        );
+        let cube_index = 16 + 36 * r + 6 * g + b;
+        let cube_colour = Self::rgb(
+            CUBE_LEVELS[r as usize],
+            CUBE_LEVELS[g as usize],
+            CUBE_LEVELS[b as usize],
+        );
+
+        let average = (u16::from(self.r) + u16::from(self.g) + u16::from(self.b)) / 3;
+        let grey_step = (average.saturating_sub(3) / 10).min(23) as u8;
+        let grey_colour = Self::greyscale(8 + 10 * grey_step);
+
+        if self.distance_squared(grey_colour) < self.distance_squared(cube_colour) {
+            232 + grey_step
+        } else {
+            cube_index
+        }
+    }
+
+    /// Return the colour of an index in the 256 colour xterm palette. The first 16 are the [`Colour::ANSI_PALETTE`], followed by a 6x6x6 colour cube and a 24 step greyscale ramp
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::core::Colour;
+    /// assert_eq!(Colour::from_ansi_256(196), Colour::rgb(255, 0, 0));
+    /// assert_eq!(Colour::from_ansi_256(Colour::rgb(255, 0, 0).to_ansi_256()), Colour::rgb(255, 0, 0));
+    /// ```
+    #[must_use]
+    pub const fn from_ansi_256(index: u8) -> Self {
+        match index {
+            0..=15 => Self::ANSI_PALETTE[index as usize],
+            16..=231 => {
+                let i = index - 16;
+                Self::rgb(
+                    CUBE_LEVELS[(i / 36) as usize],
+                    CUBE_LEVELS[(i / 6 % 6) as usize],
+                    CUBE_LEVELS[(i % 6) as usize],
+                )
+            }
+            232..=255 => Self::greyscale(8 + 10 * (index - 232)),
+        }
+    }
 }
 
 impl Add for Colour {
@@ -115,7 +232,6 @@ impl FromStr for Colour {
                 "Incorrect number of arguments, string must be in format r,g,b to be parsed correctly",
             ));
         }
-        println!("{parts:?}");
 
         let mut nums = [0u8; 3];
 
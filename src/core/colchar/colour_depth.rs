@@ -0,0 +1,125 @@
+use super::Colour;
+use std::env;
+
+/// The colour capability of the terminal being rendered to. [`Colour`]s are quantised to the nearest colour the terminal can display when rendered by a [`View`](crate::view::View)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColourDepth {
+    /// 24 bit RGB colours, written as `\x1b[38;2;{r};{g};{b}m`
+    #[default]
+    TrueColour,
+    /// The 256 colour xterm palette, written as `\x1b[38;5;{n}m`
+    Ansi256,
+    /// The 16 standard ANSI colours (see [`Colour::ANSI_PALETTE`]), written as `\x1b[{code}m`
+    Ansi16,
+    /// No colours at all. Text attributes such as bold are still written
+    None,
+}
+
+impl ColourDepth {
+    /// Detect the terminal's colour capability from the environment:
+    /// - If `NO_COLOR` is set and not empty, or `TERM` is `dumb`, returns `ColourDepth::None`
+    /// - If `COLORTERM` is `truecolor` or `24bit`, returns `ColourDepth::TrueColour`
+    /// - If `TERM` contains `256color`, returns `ColourDepth::Ansi256`
+    /// - If `TERM` is set to anything else, returns `ColourDepth::Ansi16`
+    /// - If `TERM` is not set (as is usually the case on Windows), returns `ColourDepth::TrueColour`
+    #[must_use]
+    pub fn detect() -> Self {
+        let no_color = env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        let colorterm = env::var("COLORTERM").unwrap_or_default();
+        let term = env::var("TERM").ok();
+
+        Self::from_env_values(no_color, &colorterm, term.as_deref())
+    }
+
+    fn from_env_values(no_color: bool, colorterm: &str, term: Option<&str>) -> Self {
+        if no_color || term == Some("dumb") {
+            Self::None
+        } else if matches!(colorterm, "truecolor" | "24bit") {
+            Self::TrueColour
+        } else {
+            match term {
+                Some(term) if term.contains("256color") => Self::Ansi256,
+                Some(_) => Self::Ansi16,
+                None => Self::TrueColour,
+            }
+        }
+    }
+
+    /// Return the ANSI code (without the surrounding `\x1b[` and `m`) that sets the foreground, or background if `background` is true, to the nearest displayable colour. Returns `None` for `ColourDepth::None`
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::core::{Colour, ColourDepth};
+    /// let orange = Colour::rgb(250, 130, 10);
+    /// assert_eq!(ColourDepth::TrueColour.colour_code(orange, false).unwrap(), "38;2;250;130;10");
+    /// assert_eq!(ColourDepth::Ansi256.colour_code(orange, false).unwrap(), "38;5;208");
+    /// assert_eq!(ColourDepth::Ansi16.colour_code(orange, true).unwrap(), "43");
+    /// assert_eq!(ColourDepth::None.colour_code(orange, false), None);
+    /// ```
+    #[must_use]
+    pub fn colour_code(self, colour: Colour, background: bool) -> Option<String> {
+        let layer = if background { 4 } else { 3 };
+        match self {
+            Self::TrueColour => Some(format!("{layer}8;2;{};{};{}", colour.r, colour.g, colour.b)),
+            Self::Ansi256 => Some(format!("{layer}8;5;{}", colour.to_ansi_256())),
+            Self::Ansi16 => {
+                let index = colour.to_ansi_16();
+                let code = if index < 8 {
+                    layer * 10 + index
+                } else {
+                    (layer + 6) * 10 + index - 8
+                };
+                Some(code.to_string())
+            }
+            Self::None => None,
+        }
+    }
+}
+
+/// Returns `true` if the ANSI code sets or resets the foreground or background colour
+pub const fn is_colour_code(code: u8) -> bool {
+    matches!(code, 30..=49 | 90..=97 | 100..=107)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ColourDepth;
+
+    #[test]
+    fn no_color_and_dumb_terminals_have_no_colour() {
+        assert_eq!(
+            ColourDepth::from_env_values(true, "truecolor", Some("xterm-256color")),
+            ColourDepth::None
+        );
+        assert_eq!(
+            ColourDepth::from_env_values(false, "truecolor", Some("dumb")),
+            ColourDepth::None
+        );
+    }
+
+    #[test]
+    fn colorterm_takes_priority_over_term() {
+        for colorterm in ["truecolor", "24bit"] {
+            assert_eq!(
+                ColourDepth::from_env_values(false, colorterm, Some("xterm")),
+                ColourDepth::TrueColour
+            );
+        }
+    }
+
+    #[test]
+    fn term_decides_the_palette() {
+        assert_eq!(
+            ColourDepth::from_env_values(false, "", Some("screen-256color")),
+            ColourDepth::Ansi256
+        );
+        assert_eq!(
+            ColourDepth::from_env_values(false, "", Some("linux")),
+            ColourDepth::Ansi16
+        );
+        assert_eq!(
+            ColourDepth::from_env_values(false, "", None),
+            ColourDepth::TrueColour
+        );
+    }
+}
@@ -1,18 +1,30 @@
-use std::fmt::{self, Debug, Display};
+use std::fmt::{self, Debug, Display, Write};
+use unicode_width::UnicodeWidthChar;
 
 mod colour;
+mod colour_depth;
 mod modifier;
+mod style;
 
 pub use colour::Colour;
+pub use colour_depth::ColourDepth;
 pub use modifier::Modifier;
+pub use style::{Attributes, Style};
 
-/// A coloured character. Made up of `text_char`, a single ascii character used as the "pixel" when drawn to a [`Canvas`](super::Canvas), and `modifier`, which gives that pixel a colour or makes it bold/italic
+/// A coloured character. Made up of `text_char`, a single ascii character used as the "pixel" when drawn to a [`Canvas`](super::Canvas), and `modifier`, which gives that pixel a foreground/background colour or makes it bold/italic
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ColChar {
     /// A single ascii character used as the "pixel" when drawn to a [`Canvas`](super::Canvas)
     pub text_char: char,
     /// Defines the appearance of the character - colour, bold/italic, etc.
     pub modifier: Modifier,
+    /// A combining character, such as an accent, drawn on top of `text_char`. [`Text`](crate::ascii::Text) attaches combining characters to the character before them, keeping only the first if there are several
+    pub combining: Option<char>,
+}
+
+/// Return the number of terminal columns a character takes up: 2 for wide characters such as CJK ideographs and most emoji, 0 for combining characters and other zero width characters, and 1 for everything else, including control characters
+pub fn char_width(c: char) -> usize {
+    c.width().unwrap_or(1)
 }
 
 impl ColChar {
@@ -26,16 +38,19 @@ impl ColChar {
     pub const SOLID: Self = Self {
         text_char: '█',
         modifier: Modifier::None,
+        combining: None,
     };
     /// A less solid ░ character with no [`Modifier`]
     pub const BACKGROUND: Self = Self {
         text_char: '░',
         modifier: Modifier::None,
+        combining: None,
     };
     /// A whitespace character with no [`Modifier`]
     pub const EMPTY: Self = Self {
         text_char: ' ',
         modifier: Modifier::None,
+        combining: None,
     };
     /// An opaque whitespace character (`\u{2008}`) with no [`Modifier`]
     ///
@@ -43,6 +58,23 @@ impl ColChar {
     pub const VOID: Self = Self {
         text_char: ' ', // \u{2008}
         modifier: Modifier::None,
+        combining: None,
+    };
+    /// A fully transparent `ColChar`, which is skipped when a [`View`](crate::view::View) is drawn onto another `Canvas`. Use this as the `background_char` of `View`s used as [`Layer`](crate::containers::Layer)s so that the layers below show through
+    ///
+    /// Unlike [`ColChar::EMPTY`] and [`ColChar::VOID`], this is not a real character. If a `View` containing it is displayed directly, it appears as a space
+    pub const TRANSPARENT: Self = Self {
+        text_char: '\0',
+        modifier: Modifier::None,
+        combining: None,
+    };
+    /// The second cell of a wide character such as `中` or `🦀`, which takes up two columns in the terminal. [`Text`](crate::ascii::Text) plots one after every wide character it draws, with the same `Modifier`
+    ///
+    /// When a [`View`](crate::view::View) is displayed, a `CONTINUATION` is skipped over if it follows a wide character, as the wide character already covers it. A wide character which isn't followed by a `CONTINUATION` (e.g. because something has been drawn over half of it) and a `CONTINUATION` which doesn't follow a wide character are both displayed as spaces, so that the rest of the row stays aligned
+    pub const CONTINUATION: Self = Self {
+        text_char: '\u{ffff}',
+        modifier: Modifier::None,
+        combining: None,
     };
 
     /// Create a new `ColChar` with a text character and a [`Modifier`]
@@ -51,9 +83,47 @@ impl ColChar {
         Self {
             text_char,
             modifier,
+            combining: None,
+        }
+    }
+
+    /// Returns `true` if the `ColChar` is [`ColChar::TRANSPARENT`]
+    #[must_use]
+    pub fn is_transparent(self) -> bool {
+        self == Self::TRANSPARENT
+    }
+
+    /// Returns `true` if the `ColChar` is a [`ColChar::CONTINUATION`], regardless of its `Modifier`
+    #[must_use]
+    pub const fn is_continuation(self) -> bool {
+        self.text_char == Self::CONTINUATION.text_char
+    }
+
+    /// Return the number of terminal columns the `ColChar` takes up when displayed. This is 2 for wide characters such as CJK ideographs and most emoji, which should be followed by a [`ColChar::CONTINUATION`], 0 for a `ColChar::CONTINUATION` itself and 1 for everything else
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::core::{ColChar, Modifier};
+    /// assert_eq!(ColChar::SOLID.display_width(), 1);
+    /// assert_eq!(ColChar::new('中', Modifier::None).display_width(), 2);
+    /// assert_eq!(ColChar::CONTINUATION.display_width(), 0);
+    /// ```
+    #[must_use]
+    pub fn display_width(self) -> usize {
+        if self.is_continuation() {
+            0
+        } else {
+            char_width(self.text_char).max(1)
         }
     }
 
+    /// Return a `ColChar` with the same `text_char` and modifier and a new combining character, which is drawn on top of `text_char`
+    #[must_use]
+    pub const fn with_combining(mut self, combining: char) -> Self {
+        self.combining = Some(combining);
+        self
+    }
+
     /// Return a `ColChar` with the same modifier and new `text_char`
     #[must_use]
     pub const fn with_char(mut self, text_char: char) -> Self {
@@ -89,12 +159,88 @@ impl ColChar {
         self
     }
 
-    /// Return the displayed `ColChar`, omitting the `Modifier`s where necessary
+    /// Return a `ColChar` with the same `text_char` and new `Modifier::Style` modifier
+    #[must_use]
+    pub const fn with_style(mut self, style: Style) -> Self {
+        self.modifier = Modifier::Style(style);
+        self
+    }
+
+    /// Return a `ColChar` with the same `text_char` and foreground, and a new background colour. The existing `Modifier` is converted to a [`Style`] to allow this, so `Modifier::Coded` colours are replaced with their equivalents in [`Colour::ANSI_PALETTE`]
+    #[must_use]
+    pub fn with_bg(mut self, colour: Colour) -> Self {
+        self.modifier = Modifier::Style(Style::from(self.modifier).with_bg(colour));
+        self
+    }
+
+    /// Return the `ColChar` that results from drawing `self` over `below` with the given opacity, between `0.0` (fully transparent) and `1.0` (fully opaque). Both `ColChar`s' modifiers are converted to [`Style`]s to do this, so `Modifier::Coded` colours are treated as their equivalents in [`Colour::ANSI_PALETTE`]. The result is made up of:
+    /// - The `text_char` of `self`, unless it is a space, in which case the `text_char` and foreground colour of `below` show through untouched
+    /// - Foreground and background colours blended with [`Colour::blend`]. If only one of the `ColChar`s has a colour on a layer, the other is assumed to have the terminal's default colour, taken to be [`Colour::WHITE`] for the foreground and [`Colour::BLACK`] for the background. If neither has a colour on a layer (e.g. both have `Modifier::None`), it is left as the terminal's default
+    /// - The [`Attributes`] of `self` if `opacity` is at least `0.5`, otherwise those of `below`
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::core::{ColChar, Colour, Modifier, Style};
+    /// let below = ColChar::SOLID.with_colour(Colour::rgb(200, 0, 0));
+    /// let shadow = ColChar::SOLID.with_colour(Colour::BLACK);
+    ///
+    /// let blended = shadow.blend_over(below, 0.5);
+    /// assert_eq!(blended, ColChar::SOLID.with_style(Style::new().with_fg(Colour::rgb(100, 0, 0))));
+    /// ```
+    #[must_use]
+    pub fn blend_over(self, below: Self, opacity: f64) -> Self {
+        let (above_style, below_style) = (Style::from(self.modifier), Style::from(below.modifier));
+
+        let blend_layer = |below: Option<Colour>, above: Option<Colour>, default: Colour| {
+            (below.is_some() || above.is_some()).then(|| {
+                below
+                    .unwrap_or(default)
+                    .blend(above.unwrap_or(default), opacity)
+            })
+        };
+
+        let above_is_space = self.text_char == ' ';
+        let style = Style {
+            foreground: if above_is_space {
+                below_style.foreground
+            } else {
+                blend_layer(
+                    below_style.foreground,
+                    above_style.foreground,
+                    Colour::WHITE,
+                )
+            },
+            background: blend_layer(
+                below_style.background,
+                above_style.background,
+                Colour::BLACK,
+            ),
+            attributes: if opacity >= 0.5 {
+                above_style.attributes
+            } else {
+                below_style.attributes
+            },
+        };
+
+        let top = if above_is_space { below } else { self };
+        Self {
+            text_char: top.text_char,
+            combining: top.combining,
+            modifier: if style.is_empty() {
+                Modifier::None
+            } else {
+                Modifier::Style(style)
+            },
+        }
+    }
+
+    /// Return the displayed `ColChar`, omitting the `Modifier`s where necessary and quantising its colours to the given [`ColourDepth`]
     pub(crate) fn display_with_prev_and_next(
         self,
         f: &mut fmt::Formatter,
         prev_mod: Option<Modifier>,
         next_mod: Option<Modifier>,
+        depth: ColourDepth,
     ) -> fmt::Result {
         let modifier = if prev_mod == Some(self.modifier) {
             Modifier::None
@@ -107,7 +253,18 @@ impl ColChar {
             Modifier::END
         };
 
-        write!(f, "{}{}{}", modifier, self.text_char, end)
+        let text_char = if self.is_transparent() {
+            ' '
+        } else {
+            self.text_char
+        };
+
+        modifier.write_with_depth(f, depth)?;
+        f.write_char(text_char)?;
+        if let Some(combining) = self.combining {
+            f.write_char(combining)?;
+        }
+        write!(f, "{end}")
     }
 }
 
@@ -118,10 +275,21 @@ impl Default for ColChar {
 }
 
 impl Display for ColChar {
+    /// Write the `ColChar`'s `text_char` and combining character, with its `Modifier`. A [`ColChar::CONTINUATION`] writes nothing, as the wide character before it already covers its column
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_continuation() {
+            return Ok(());
+        }
+        let combining = self.combining.map(String::from).unwrap_or_default();
         match self.modifier {
-            Modifier::None => write!(f, "{}", self.text_char),
-            _ => write!(f, "{}{}{}", self.modifier, self.text_char, Modifier::END),
+            Modifier::None => write!(f, "{}{combining}", self.text_char),
+            _ => write!(
+                f,
+                "{}{}{combining}{}",
+                self.modifier,
+                self.text_char,
+                Modifier::END
+            ),
         }
     }
 }
@@ -1,5 +1,5 @@
-use super::Colour;
-use std::fmt::Display;
+use super::{Colour, ColourDepth, Style, colour_depth};
+use std::fmt::{self, Display};
 
 /// The [`Modifier`] enum is used for adding modifications to text such as colour, bold/italic/underline and others. `Modifier` should be used through [`ColChar`](super::ColChar).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -10,10 +10,14 @@ pub enum Modifier {
     ///
     /// See <https://prirai.github.io/blogs/ansi-esc/#colors-graphics-mode> for a guide to available code
     Coded(u8),
-    /// `Colour(`[`Colour`](Colour)`)` unwraps to `\x1b[38;2;{r};{g};{b}m`, where `(r, g, b)` together represent a 24 bit RGB value
+    /// <code>Colour([Colour])</code> unwraps to `\x1b[38;2;{r};{g};{b}m`, where `(r, g, b)` together represent a 24 bit RGB value
     ///
     /// Not all terminals support RGB ANSI escape codes, in which case you will have to resort to `Modifier::Coded` for colours. Some `Coded` colours are available as constants, e.g. [`Modifier::RED`]
     Colour(Colour),
+    /// <code>Style([Style])</code> unwraps to a single escape code combining a foreground colour, background colour and any number of [`Attributes`](super::Attributes), such as `\x1b[1;38;2;{r};{g};{b};48;2;{r};{g};{b}m`
+    ///
+    /// Use this when you need a background colour, or a colour alongside bold, underline, etc.
+    Style(Style),
     /// `None` unwraps to nothing. It does not change the current applied modifiers.
     #[default]
     None,
@@ -46,14 +50,38 @@ impl Modifier {
     pub fn from_hsv(h: u8, s: u8, v: u8) -> Self {
         Self::Colour(Colour::hsv(h, s, v))
     }
-}
 
-impl Display for Modifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    /// Write the `Modifier`'s escape code with its colours quantised to the given [`ColourDepth`]. `Coded` colours are left as they are, unless the depth is `ColourDepth::None`, in which case they are omitted
+    pub(crate) fn write_with_depth(
+        self,
+        f: &mut fmt::Formatter,
+        depth: ColourDepth,
+    ) -> fmt::Result {
         match self {
+            Self::Coded(code)
+                if depth == ColourDepth::None && colour_depth::is_colour_code(code) =>
+            {
+                Ok(())
+            }
             Self::Coded(code) => write!(f, "\x1b[{code}m"),
-            Self::Colour(c) => write!(f, "\x1b[38;2;{};{};{}m", c.r, c.g, c.b),
+            Self::Colour(c) => depth
+                .colour_code(c, false)
+                .map_or(Ok(()), |code| write!(f, "\x1b[{code}m")),
+            Self::Style(style) => style.write_with_depth(f, depth),
             Self::None => Ok(()),
         }
     }
 }
+
+impl From<Style> for Modifier {
+    fn from(style: Style) -> Self {
+        Self::Style(style)
+    }
+}
+
+impl Display for Modifier {
+    /// Writes the `Modifier`'s escape code in true colour. [`View`](crate::view::View) uses its `colour_depth` instead
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_with_depth(f, ColourDepth::TrueColour)
+    }
+}
@@ -0,0 +1,200 @@
+use super::{Colour, ColourDepth, Modifier};
+use std::{
+    fmt::{self, Display},
+    ops::{BitOr, BitOrAssign},
+};
+
+/// A set of text attributes such as bold, italic or underline, used by [`Style`]. Attributes can be combined with `|`, for example `Attributes::BOLD | Attributes::UNDERLINE`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct Attributes(u8);
+
+impl Attributes {
+    /// No attributes
+    pub const NONE: Self = Self(0);
+    /// Bold or increased intensity text (`\x1b[1m`)
+    pub const BOLD: Self = Self(1 << 0);
+    /// Faint or decreased intensity text (`\x1b[2m`)
+    pub const DIM: Self = Self(1 << 1);
+    /// Italic text (`\x1b[3m`)
+    pub const ITALIC: Self = Self(1 << 2);
+    /// Underlined text (`\x1b[4m`)
+    pub const UNDERLINE: Self = Self(1 << 3);
+    /// Blinking text (`\x1b[5m`)
+    pub const BLINK: Self = Self(1 << 4);
+    /// Swaps the foreground and background colours (`\x1b[7m`)
+    pub const REVERSE: Self = Self(1 << 5);
+    /// Crossed out text (`\x1b[9m`)
+    pub const STRIKETHROUGH: Self = Self(1 << 6);
+
+    /// Every attribute alongside its name and ANSI code
+    pub(crate) const ALL: [(Self, &'static str, u8); 7] = [
+        (Self::BOLD, "bold", 1),
+        (Self::DIM, "dim", 2),
+        (Self::ITALIC, "italic", 3),
+        (Self::UNDERLINE, "underline", 4),
+        (Self::BLINK, "blink", 5),
+        (Self::REVERSE, "reverse", 7),
+        (Self::STRIKETHROUGH, "strikethrough", 9),
+    ];
+
+    /// Returns `true` if every attribute in `other` is also in `self`
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if no attributes are set
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Return the `Attributes` with the attributes in `other` added
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Return the `Attributes` represented by an ANSI code, or `None` if the code is not an attribute
+    pub(crate) fn from_code(code: u8) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|(_, _, c)| *c == code)
+            .map(|(attribute, _, _)| *attribute)
+    }
+
+    /// Return an iterator over the ANSI codes of the set attributes
+    pub(crate) fn codes(self) -> impl Iterator<Item = u8> {
+        Self::ALL
+            .into_iter()
+            .filter(move |(attribute, _, _)| self.contains(*attribute))
+            .map(|(_, _, code)| code)
+    }
+}
+
+impl BitOr for Attributes {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for Attributes {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+/// A combination of an optional foreground [`Colour`], an optional background [`Colour`] and a set of [`Attributes`]
+///
+/// `Style` should be used through [`Modifier::Style`], which can be created from a `Style` with `.into()`
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::core::{Attributes, ColChar, Colour, Style};
+/// let style = Style::new()
+///     .with_fg(Colour::WHITE)
+///     .with_bg(Colour::rgb(0, 0, 128))
+///     .with_attributes(Attributes::BOLD | Attributes::UNDERLINE);
+///
+/// let c = ColChar::SOLID.with_style(style);
+/// assert_eq!(c.to_string(), "\x1b[1;4;38;2;255;255;255;48;2;0;0;128m█\x1b[0m");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Style {
+    /// The colour of the text, or `None` to use the terminal's default
+    pub foreground: Option<Colour>,
+    /// The colour behind the text, or `None` to use the terminal's default
+    pub background: Option<Colour>,
+    /// The text attributes such as bold or underline
+    pub attributes: Attributes,
+}
+
+impl Style {
+    /// A `Style` with no colours or attributes
+    pub const NONE: Self = Self::new();
+
+    /// Create a new `Style` with no colours or attributes
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            foreground: None,
+            background: None,
+            attributes: Attributes::NONE,
+        }
+    }
+
+    /// Return the `Style` with an updated foreground colour
+    #[must_use]
+    pub const fn with_fg(mut self, colour: Colour) -> Self {
+        self.foreground = Some(colour);
+        self
+    }
+
+    /// Return the `Style` with an updated background colour
+    #[must_use]
+    pub const fn with_bg(mut self, colour: Colour) -> Self {
+        self.background = Some(colour);
+        self
+    }
+
+    /// Return the `Style` with the given [`Attributes`] added to its existing ones
+    #[must_use]
+    pub const fn with_attributes(mut self, attributes: Attributes) -> Self {
+        self.attributes = self.attributes.union(attributes);
+        self
+    }
+
+    /// Returns `true` if the `Style` has no colours or attributes, and so will not change the text's appearance
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.foreground.is_none() && self.background.is_none() && self.attributes.is_empty()
+    }
+
+    /// Write all of the `Style`'s codes as a single escape code, with its colours quantised to the given [`ColourDepth`]. Writes nothing if there are no codes to write
+    pub(crate) fn write_with_depth(
+        self,
+        f: &mut fmt::Formatter,
+        depth: ColourDepth,
+    ) -> fmt::Result {
+        let mut codes: Vec<String> = self.attributes.codes().map(|c| c.to_string()).collect();
+        codes.extend(self.foreground.and_then(|c| depth.colour_code(c, false)));
+        codes.extend(self.background.and_then(|c| depth.colour_code(c, true)));
+
+        if codes.is_empty() {
+            Ok(())
+        } else {
+            write!(f, "\x1b[{}m", codes.join(";"))
+        }
+    }
+}
+
+impl From<Modifier> for Style {
+    /// Convert a [`Modifier`] to a `Style`. `Modifier::Coded` attribute codes and the standard foreground and background colour codes (30-37, 40-47, 90-97 and 100-107) are converted to their equivalents in [`Colour::ANSI_PALETTE`]. Any other codes are discarded
+    fn from(modifier: Modifier) -> Self {
+        match modifier {
+            Modifier::Coded(code) => {
+                let palette = |i: u8| Some(Colour::ANSI_PALETTE[usize::from(i)]);
+                let mut style = Self::new();
+                match code {
+                    30..=37 => style.foreground = palette(code - 30),
+                    90..=97 => style.foreground = palette(code - 90 + 8),
+                    40..=47 => style.background = palette(code - 40),
+                    100..=107 => style.background = palette(code - 100 + 8),
+                    _ => style.attributes = Attributes::from_code(code).unwrap_or_default(),
+                }
+                style
+            }
+            Modifier::Colour(colour) => Self::new().with_fg(colour),
+            Modifier::Style(style) => style,
+            Modifier::None => Self::new(),
+        }
+    }
+}
+
+impl Display for Style {
+    /// Writes all of the `Style`'s codes as a single ANSI escape code in true colour, or nothing if the style is empty
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_with_depth(f, ColourDepth::TrueColour)
+    }
+}
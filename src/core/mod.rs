@@ -23,7 +23,8 @@
 //! There you have it! You've written your first program with Gemini! This is still a work in progress, so any feedback or issue requests would be appreciated :)
 
 mod colchar;
-pub use colchar::{ColChar, Colour, Modifier};
+pub(crate) use colchar::char_width;
+pub use colchar::{Attributes, ColChar, Colour, ColourDepth, Modifier, Style};
 
 /// An alias to [`I64Vec2`](glam::I64Vec2), a two-dimensional vector of `i64` values
 pub type Vec2D = glam::I64Vec2;
@@ -34,6 +35,12 @@ pub type Vec2D = glam::I64Vec2;
 pub trait Canvas: Sized {
     /// Plot a [`ColChar`] to the `Canvas` at `pos`
     fn plot(&mut self, pos: Vec2D, c: ColChar);
+
+    /// Plot a [`ColChar`] to the `Canvas` at `pos`, `depth` units away from the viewer. A `Canvas` with a depth buffer (such as a [`View`](crate::view::View) created with [`with_depth_buffer`](crate::view::View::with_depth_buffer)) will only plot the `ColChar` if it is closer than what has already been plotted there. By default the depth is ignored and the `ColChar` is plotted with [`Canvas::plot`]
+    fn plot_with_depth(&mut self, pos: Vec2D, c: ColChar, depth: f64) {
+        let _ = depth;
+        self.plot(pos, c);
+    }
 }
 
 /// A struct that can draw to a [`Canvas`]
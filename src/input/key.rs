@@ -0,0 +1,121 @@
+use std::ops::{BitOr, BitOrAssign};
+
+/// A key on the keyboard, as reported by the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A key that types a character. Shifted letters are reported as their uppercase character rather than with [`KeyModifiers::SHIFT`], so `Shift+A` is `Key::Char('A')`
+    Char(char),
+    /// The enter or return key
+    Enter,
+    /// The tab key. `Shift+Tab` is reported as `Key::Tab` with [`KeyModifiers::SHIFT`]
+    Tab,
+    /// The backspace key
+    Backspace,
+    /// The escape key
+    Esc,
+    /// The up arrow key
+    Up,
+    /// The down arrow key
+    Down,
+    /// The left arrow key
+    Left,
+    /// The right arrow key
+    Right,
+    /// The home key
+    Home,
+    /// The end key
+    End,
+    /// The page up key
+    PageUp,
+    /// The page down key
+    PageDown,
+    /// The insert key
+    Insert,
+    /// The delete key
+    Delete,
+    /// A function key, from `F(1)` to `F(12)`
+    F(u8),
+}
+
+/// A set of modifier keys held alongside a [`Key`]. Modifiers can be combined with `|`, for example `KeyModifiers::CONTROL | KeyModifiers::SHIFT`
+///
+/// Terminals can only report modifiers for some keys. `Ctrl` is reported for letters and most special keys, `Alt` for most keys, and `Shift` only for special keys such as the arrow keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct KeyModifiers(u8);
+
+impl KeyModifiers {
+    /// No modifiers
+    pub const NONE: Self = Self(0);
+    /// The shift key
+    pub const SHIFT: Self = Self(1 << 0);
+    /// The alt (or option) key
+    pub const ALT: Self = Self(1 << 1);
+    /// The control key
+    pub const CONTROL: Self = Self(1 << 2);
+
+    /// Returns `true` if every modifier in `other` is also in `self`
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if no modifiers are set
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Return the `KeyModifiers` with the modifiers in `other` added
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Return the `KeyModifiers` encoded in the modifier parameter of an escape sequence, such as the `5` in `\x1b[1;5A` (`Ctrl+Up`)
+    pub(crate) const fn from_parameter(parameter: u16) -> Self {
+        Self(parameter.saturating_sub(1) as u8 & 0b111)
+    }
+}
+
+impl BitOr for KeyModifiers {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for KeyModifiers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+/// A [`Key`] pressed with a set of [`KeyModifiers`]. A `Key` can be converted into a `KeyEvent` with no modifiers using `.into()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyEvent {
+    /// The key that was pressed
+    pub key: Key,
+    /// The modifier keys held while it was pressed
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyEvent {
+    /// Create a new `KeyEvent`
+    #[must_use]
+    pub const fn new(key: Key, modifiers: KeyModifiers) -> Self {
+        Self { key, modifiers }
+    }
+
+    /// Return the `KeyEvent` with the modifiers in `modifiers` added to its existing ones
+    #[must_use]
+    pub const fn with_modifiers(mut self, modifiers: KeyModifiers) -> Self {
+        self.modifiers = self.modifiers.union(modifiers);
+        self
+    }
+}
+
+impl From<Key> for KeyEvent {
+    fn from(key: Key) -> Self {
+        Self::new(key, KeyModifiers::NONE)
+    }
+}
@@ -0,0 +1,121 @@
+use super::{InputEvent, KeyEvent};
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+/// Tracks which keys are pressed, held and released each frame from a stream of [`InputEvent`]s
+///
+/// Call [`KeyboardState::update`] once per frame with that frame's events, or use [`Input`](super::Input) to do this for you
+///
+/// Terminals only report when a key is pressed, and then repeatedly while it is held down, and never when it is released. A key is therefore treated as held until no event has been received for it for `hold_timeout`, at which point it is released. Set `hold_timeout` to slightly longer than the delay before your keyboard starts repeating keys so that held keys aren't released in between
+///
+/// Keys are tracked with their modifiers, so `Ctrl+C` is a different key to `C`
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::input::{InputEvent, Key, KeyboardState};
+/// # use std::time::{Duration, Instant};
+/// let mut keyboard = KeyboardState::new().with_hold_timeout(Duration::from_millis(100));
+/// let start = Instant::now();
+/// let up = [InputEvent::Key(Key::Up.into())];
+///
+/// keyboard.update(&up, start);
+/// assert!(keyboard.is_pressed(Key::Up) && keyboard.is_held(Key::Up));
+///
+/// keyboard.update(&up, start + Duration::from_millis(50));
+/// assert!(!keyboard.is_pressed(Key::Up) && keyboard.is_held(Key::Up));
+///
+/// keyboard.update(&[], start + Duration::from_millis(200));
+/// assert!(keyboard.is_released(Key::Up) && !keyboard.is_held(Key::Up));
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyboardState {
+    /// How long a key stays held after the last event received for it
+    pub hold_timeout: Duration,
+    held: HashMap<KeyEvent, Instant>,
+    pressed: Vec<KeyEvent>,
+    released: Vec<KeyEvent>,
+}
+
+impl KeyboardState {
+    /// Create a new `KeyboardState` with no keys held and a `hold_timeout` of 500 milliseconds
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            hold_timeout: Duration::from_millis(500),
+            held: HashMap::new(),
+            pressed: vec![],
+            released: vec![],
+        }
+    }
+
+    /// Return the `KeyboardState` with an updated `hold_timeout` property. Consumes the original `KeyboardState`
+    #[must_use]
+    pub const fn with_hold_timeout(mut self, hold_timeout: Duration) -> Self {
+        self.hold_timeout = hold_timeout;
+        self
+    }
+
+    /// Update the state with the events received since the last update. `now` should be the current time, which is used to release keys that haven't been received for `hold_timeout`. Events other than key events are ignored
+    pub fn update(&mut self, events: &[InputEvent], now: Instant) {
+        self.pressed.clear();
+        self.released.clear();
+
+        for key in events.iter().filter_map(|event| event.key_event()) {
+            if !self.held.contains_key(&key) && !self.pressed.contains(&key) {
+                self.pressed.push(key);
+            }
+            self.held.insert(key, now);
+        }
+
+        self.held.retain(|key, last_seen| {
+            let is_held = now.saturating_duration_since(*last_seen) < self.hold_timeout;
+            if !is_held {
+                self.released.push(*key);
+            }
+            is_held
+        });
+    }
+
+    /// Returns `true` if the key started being held this frame
+    #[must_use]
+    pub fn is_pressed(&self, key: impl Into<KeyEvent>) -> bool {
+        self.pressed.contains(&key.into())
+    }
+
+    /// Returns `true` if the key is currently held, including the frame it was pressed in
+    #[must_use]
+    pub fn is_held(&self, key: impl Into<KeyEvent>) -> bool {
+        self.held.contains_key(&key.into())
+    }
+
+    /// Returns `true` if the key stopped being held this frame
+    #[must_use]
+    pub fn is_released(&self, key: impl Into<KeyEvent>) -> bool {
+        self.released.contains(&key.into())
+    }
+
+    /// Return the keys that started being held this frame, in the order they were pressed
+    #[must_use]
+    pub fn pressed(&self) -> &[KeyEvent] {
+        &self.pressed
+    }
+
+    /// Return an iterator over the keys that are currently held, in no particular order
+    pub fn held(&self) -> impl Iterator<Item = KeyEvent> + '_ {
+        self.held.keys().copied()
+    }
+
+    /// Return the keys that stopped being held this frame
+    #[must_use]
+    pub fn released(&self) -> &[KeyEvent] {
+        &self.released
+    }
+}
+
+impl Default for KeyboardState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
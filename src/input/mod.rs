@@ -0,0 +1,196 @@
+//! This module handles keyboard and mouse input from the terminal.
+//!
+//! [`Input`] reads `stdin` in the background without blocking, parses what it reads into [`InputEvent`]s (key presses, [`MouseEvent`]s and terminal resizes) and keeps track of which keys are pressed, held and released each frame with a [`KeyboardState`]
+//!
+//! For keys to be received as soon as they are pressed rather than once enter is pressed, the terminal must be in raw mode. Start a [`TerminalSession`](crate::view::TerminalSession) with raw mode enabled before creating an `Input`. Remember that `Ctrl+C` no longer stops the program in raw mode, so you should handle it yourself
+//!
+//! ## Example
+//! ```no_run
+//! use gemini_engine::{
+//!     gameloop::MainLoopRoot,
+//!     input::{Input, Key, KeyEvent, KeyModifiers},
+//!     view::{SessionOptions, TerminalSession},
+//! };
+//!
+//! struct Game {
+//!     session: Option<TerminalSession>,
+//!     input: Input,
+//!     x: i64,
+//! }
+//!
+//! impl MainLoopRoot for Game {
+//!     fn get_fps(&self) -> f32 {
+//!         30.0
+//!     }
+//!
+//!     fn frame(&mut self) {
+//!         self.input.update();
+//!         if self.input.keyboard.is_held(Key::Left) {
+//!             self.x -= 1;
+//!         }
+//!         if self.input.keyboard.is_pressed(KeyEvent::new(Key::Char('c'), KeyModifiers::CONTROL)) {
+//!             // Restore the terminal before exiting, as `exit` doesn't run destructors
+//!             drop(self.session.take());
+//!             std::process::exit(0);
+//!         }
+//!     }
+//!
+//!     fn render_frame(&mut self) {}
+//! }
+//!
+//! let session = TerminalSession::new(SessionOptions::default().with_raw_mode()).unwrap();
+//! let mut game = Game {
+//!     session: Some(session),
+//!     input: Input::new().unwrap(),
+//!     x: 0,
+//! };
+//! game.main_loop();
+//! ```
+
+use crate::{core::Vec2D, view::ResizeListener};
+use std::{
+    io::{self, Read},
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::{Duration, Instant},
+};
+
+mod key;
+mod keyboard_state;
+mod mouse;
+mod parser;
+
+pub use key::{Key, KeyEvent, KeyModifiers};
+pub use keyboard_state::KeyboardState;
+pub use mouse::{MouseButton, MouseEvent, MouseEventKind};
+pub use parser::InputParser;
+
+/// How long [`Input`] waits for the rest of an escape sequence before deciding that an escape byte on its own was the escape key
+const ESCAPE_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// An event read from the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InputEvent {
+    /// A key was pressed, or is being held down and was repeated
+    Key(KeyEvent),
+    /// A mouse button was pressed or released, the mouse was moved or the scroll wheel was scrolled
+    Mouse(MouseEvent),
+    /// The terminal was resized to the given size, as reported by a [`ResizeListener`]
+    Resize(Vec2D),
+}
+
+impl InputEvent {
+    /// Return the [`KeyEvent`] if this is a key event
+    #[must_use]
+    pub const fn key_event(self) -> Option<KeyEvent> {
+        match self {
+            Self::Key(event) => Some(event),
+            Self::Mouse(_) | Self::Resize(_) => None,
+        }
+    }
+
+    /// Return the [`MouseEvent`] if this is a mouse event
+    #[must_use]
+    pub const fn mouse_event(self) -> Option<MouseEvent> {
+        match self {
+            Self::Mouse(event) => Some(event),
+            Self::Key(_) | Self::Resize(_) => None,
+        }
+    }
+}
+
+/// Reads and parses input from `stdin` on a background thread, so that it can be checked every frame without blocking
+///
+/// Call [`Input::update`] at the start of every frame to collect the events received since the last frame and update the [`KeyboardState`]
+///
+/// Only one `Input` should exist at a time, as each one reads from `stdin` and would take events from the others. The background thread stops once `stdin` is closed or the `Input` is dropped and more input is received
+///
+/// As the escape key sends the same byte that starts every escape sequence, it is only reported once no more input has arrived for a short time after it
+#[derive(Debug)]
+pub struct Input {
+    /// Which keys are pressed, held and released this frame
+    pub keyboard: KeyboardState,
+    receiver: Receiver<Vec<u8>>,
+    parser: InputParser,
+    last_received: Instant,
+    events: Vec<InputEvent>,
+    mouse_pos: Option<Vec2D>,
+    resize_listener: ResizeListener,
+}
+
+impl Input {
+    /// Create a new `Input`, starting the thread that reads from `stdin`
+    ///
+    /// # Errors
+    /// Returns an error if the thread couldn't be spawned
+    pub fn new() -> io::Result<Self> {
+        let (sender, receiver) = mpsc::channel();
+
+        thread::Builder::new()
+            .name(String::from("gemini-input"))
+            .spawn(move || {
+                let mut stdin = io::stdin();
+                let mut buffer = [0; 1024];
+
+                while let Ok(length @ 1..) = stdin.read(&mut buffer) {
+                    if sender.send(buffer[..length].to_vec()).is_err() {
+                        return;
+                    }
+                }
+            })?;
+
+        Ok(Self {
+            keyboard: KeyboardState::new(),
+            receiver,
+            parser: InputParser::new(),
+            last_received: Instant::now(),
+            events: vec![],
+            mouse_pos: None,
+            resize_listener: ResizeListener::new(),
+        })
+    }
+
+    /// Return the `Input` with the given [`KeyboardState`], for example one with a different `hold_timeout`. Consumes the original `Input`
+    #[must_use]
+    pub fn with_keyboard_state(mut self, keyboard: KeyboardState) -> Self {
+        self.keyboard = keyboard;
+        self
+    }
+
+    /// Collect every event received since the last call and update the [`KeyboardState`] with them. This should be called once at the start of every frame
+    pub fn update(&mut self) {
+        let now = Instant::now();
+        self.events.clear();
+        for bytes in self.receiver.try_iter() {
+            self.events.extend(self.parser.parse(&bytes));
+            self.last_received = now;
+        }
+        if now.duration_since(self.last_received) >= ESCAPE_TIMEOUT {
+            self.events.extend(self.parser.flush());
+        }
+        self.events
+            .extend(self.resize_listener.poll().map(InputEvent::Resize));
+        self.keyboard.update(&self.events, now);
+        if let Some(event) = self.mouse_events().last() {
+            self.mouse_pos = Some(event.pos);
+        }
+    }
+
+    /// Return the events collected by the last call to [`Input::update`], in the order they were received
+    #[must_use]
+    pub fn events(&self) -> &[InputEvent] {
+        &self.events
+    }
+
+    /// Return an iterator over the [`MouseEvent`]s collected by the last call to [`Input::update`], in the order they were received
+    pub fn mouse_events(&self) -> impl Iterator<Item = MouseEvent> + '_ {
+        self.events.iter().filter_map(|event| event.mouse_event())
+    }
+
+    /// Return the position of the mouse as of the last mouse event received, or `None` if no mouse events have been received yet. See [`MouseEvent::pos`]
+    #[must_use]
+    pub const fn mouse_pos(&self) -> Option<Vec2D> {
+        self.mouse_pos
+    }
+}
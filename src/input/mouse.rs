@@ -0,0 +1,116 @@
+use super::KeyModifiers;
+use crate::{containers::CanCollide, core::Vec2D, view::View};
+
+/// A button on the mouse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    /// The left mouse button
+    Left,
+    /// The middle mouse button, usually the scroll wheel
+    Middle,
+    /// The right mouse button
+    Right,
+}
+
+/// What happened in a [`MouseEvent`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseEventKind {
+    /// A button was pressed
+    Press(MouseButton),
+    /// A button was released
+    Release(MouseButton),
+    /// The mouse was moved while a button was held down
+    Drag(MouseButton),
+    /// The mouse was moved with no buttons held down
+    Move,
+    /// The scroll wheel was scrolled up
+    ScrollUp,
+    /// The scroll wheel was scrolled down
+    ScrollDown,
+    /// The scroll wheel was scrolled left
+    ScrollLeft,
+    /// The scroll wheel was scrolled right
+    ScrollRight,
+}
+
+/// A mouse event reported by the terminal
+///
+/// The terminal only reports mouse events while mouse capture is enabled, which you can do by starting a [`TerminalSession`](crate::view::TerminalSession) with [`SessionOptions::with_mouse_capture`](crate::view::SessionOptions::with_mouse_capture)
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::{input::{InputParser, MouseButton, MouseEventKind}, core::{ColChar, Vec2D}, primitives::Rect};
+/// let mut parser = InputParser::new();
+/// let click = parser.parse(b"\x1b[<0;3;2M")[0].mouse_event().unwrap();
+///
+/// assert_eq!(click.kind, MouseEventKind::Press(MouseButton::Left));
+/// assert_eq!(click.pos, Vec2D::new(2, 1));
+///
+/// let button = Rect::new(Vec2D::new(1, 1), Vec2D::new(4, 1), ColChar::SOLID);
+/// assert!(click.hits(&button));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MouseEvent {
+    /// What happened
+    pub kind: MouseEventKind,
+    /// The cell the mouse was over, with `(0,0)` being the top left corner of the terminal. As [`View::display_render`] draws the `View` from the top left corner, this is also the position in the rendered `View`
+    pub pos: Vec2D,
+    /// The modifier keys held during the event. Many terminals use some modifiers for their own features, such as selecting text with `Shift`, and so won't report them
+    pub modifiers: KeyModifiers,
+}
+
+impl MouseEvent {
+    /// Create a `MouseEvent` from the parameters of an SGR (1006) mouse report, `\x1b[<{code};{x};{y}M`, or `m` at the end if a button was released. Returns `None` if the code doesn't describe an event
+    pub(crate) fn from_sgr(code: u16, x: u16, y: u16, released: bool) -> Option<Self> {
+        let button = match code & 0b11 {
+            0 => Some(MouseButton::Left),
+            1 => Some(MouseButton::Middle),
+            2 => Some(MouseButton::Right),
+            _ => None,
+        };
+
+        let kind = if code & 64 != 0 {
+            match code & 0b11 {
+                0 => MouseEventKind::ScrollUp,
+                1 => MouseEventKind::ScrollDown,
+                2 => MouseEventKind::ScrollLeft,
+                _ => MouseEventKind::ScrollRight,
+            }
+        } else if code & 32 != 0 {
+            button.map_or(MouseEventKind::Move, MouseEventKind::Drag)
+        } else if released {
+            MouseEventKind::Release(button?)
+        } else {
+            MouseEventKind::Press(button?)
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for (bit, modifier) in [
+            (4, KeyModifiers::SHIFT),
+            (8, KeyModifiers::ALT),
+            (16, KeyModifiers::CONTROL),
+        ] {
+            if code & bit != 0 {
+                modifiers |= modifier;
+            }
+        }
+
+        Some(Self {
+            kind,
+            pos: Vec2D::new(i64::from(x) - 1, i64::from(y) - 1),
+            modifiers,
+        })
+    }
+
+    /// Return the position of the event in the [`View`], or `None` if it was outside of the `View`. This assumes that the `View` was rendered with [`View::display_render`], which draws it from the top left corner of the terminal
+    #[must_use]
+    pub fn view_pos(&self, view: &View) -> Option<Vec2D> {
+        (self.pos.cmpge(Vec2D::ZERO).all() && self.pos.cmplt(view.size()).all()).then_some(self.pos)
+    }
+
+    /// Returns `true` if the event happened over the collider, such as a [`Rect`](crate::primitives::Rect) or a [`CollisionContainer`](crate::containers::CollisionContainer). To find out which element of a `CollisionContainer` was hit, use [`CollisionContainer::element_at`](crate::containers::CollisionContainer::element_at) with the event's `pos`
+    #[must_use]
+    pub fn hits(&self, collider: &(impl CanCollide + ?Sized)) -> bool {
+        collider.collides_with_pos(self.pos)
+    }
+}
@@ -0,0 +1,270 @@
+use super::{InputEvent, Key, KeyEvent, KeyModifiers, MouseEvent};
+
+/// The longest escape sequence the parser will wait to complete. Anything longer is assumed not to be an escape sequence, so only its escape byte is discarded and the rest is parsed as normal input
+const MAX_SEQUENCE_LENGTH: usize = 32;
+
+/// The result of trying to parse a single event from the start of a byte slice
+enum Parsed {
+    /// An event, and the number of bytes it was made of
+    Event(InputEvent, usize),
+    /// A number of bytes that don't make up an event we recognise
+    Skip(usize),
+    /// The slice ends partway through an escape sequence
+    Incomplete,
+}
+
+/// Parses the bytes a terminal sends to `stdin` into [`InputEvent`]s. Escape sequences split between calls to [`InputParser::parse`] are held onto until they are complete
+///
+/// An escape byte on its own at the end of the input is held onto, as the rest of an escape sequence may still be on its way. Call [`InputParser::flush`] once no more input has arrived for a short time to report it as the escape key. [`Input`](super::Input) does this for you
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::input::{InputEvent, InputParser, Key, KeyEvent, KeyModifiers};
+/// let mut parser = InputParser::new();
+///
+/// let events = parser.parse(b"a\x1b[1;5A\x03");
+/// assert_eq!(events, vec![
+///     InputEvent::Key(Key::Char('a').into()),
+///     InputEvent::Key(KeyEvent::new(Key::Up, KeyModifiers::CONTROL)),
+///     InputEvent::Key(KeyEvent::new(Key::Char('c'), KeyModifiers::CONTROL)),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InputParser {
+    pending: Vec<u8>,
+}
+
+impl InputParser {
+    /// Create a new `InputParser`
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { pending: vec![] }
+    }
+
+    /// Parse the bytes into events, including any bytes left over from the previous call
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::input::{InputEvent, InputParser, Key};
+    /// let mut parser = InputParser::new();
+    ///
+    /// // An escape sequence that never ends only loses its escape byte, so the keys after it still arrive
+    /// let mut bytes = b"\x1b[".to_vec();
+    /// bytes.extend([b'1'; 40]);
+    /// let events = parser.parse(&bytes);
+    /// assert_eq!(events.len(), 41);
+    /// assert_eq!(events[0], InputEvent::Key(Key::Char('[').into()));
+    ///
+    /// // As does an escape sequence interrupted by another one
+    /// let events = parser.parse(b"\x1b[12\x1b[Aq");
+    /// assert_eq!(events, vec![
+    ///     InputEvent::Key(Key::Up.into()),
+    ///     InputEvent::Key(Key::Char('q').into()),
+    /// ]);
+    /// ```
+    pub fn parse(&mut self, bytes: &[u8]) -> Vec<InputEvent> {
+        self.pending.extend_from_slice(bytes);
+
+        let mut events = vec![];
+        let mut i = 0;
+        while i < self.pending.len() {
+            match parse_event(&self.pending[i..]) {
+                Parsed::Event(event, length) => {
+                    events.push(event);
+                    i += length;
+                }
+                Parsed::Skip(length) => i += length,
+                Parsed::Incomplete => break,
+            }
+        }
+        self.pending.drain(..i);
+
+        events
+    }
+
+    /// Give up on waiting for the rest of any incomplete escape sequence, reporting the escape byte that started it as the escape key and parsing the bytes after it as normal input. This should be called when no more input has arrived for a short time
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::input::{InputEvent, InputParser, Key};
+    /// let mut parser = InputParser::new();
+    ///
+    /// // The escape byte could be the start of an escape sequence, which arrives with the next read
+    /// assert_eq!(parser.parse(b"\x1b"), vec![]);
+    /// assert_eq!(parser.parse(b"[A"), vec![InputEvent::Key(Key::Up.into())]);
+    ///
+    /// // Nothing else arrives, so it was the escape key
+    /// assert_eq!(parser.parse(b"\x1b"), vec![]);
+    /// assert_eq!(parser.flush(), vec![InputEvent::Key(Key::Esc.into())]);
+    /// ```
+    pub fn flush(&mut self) -> Vec<InputEvent> {
+        let mut events = self.parse(&[]);
+        while let Some(&byte) = self.pending.first() {
+            self.pending.remove(0);
+            // Anything else left over is an incomplete UTF-8 character, which is dropped
+            if byte == 0x1b {
+                events.push(InputEvent::Key(Key::Esc.into()));
+            }
+            events.extend(self.parse(&[]));
+        }
+
+        events
+    }
+}
+
+const fn key_event(key: Key, modifiers: KeyModifiers, length: usize) -> Parsed {
+    Parsed::Event(InputEvent::Key(KeyEvent::new(key, modifiers)), length)
+}
+
+fn parse_event(bytes: &[u8]) -> Parsed {
+    match bytes {
+        // An escape byte on its own could be the start of an escape sequence that hasn't fully arrived
+        [] | [0x1b] => Parsed::Incomplete,
+        [0x1b, 0x1b, ..] => key_event(Key::Esc, KeyModifiers::NONE, 1),
+        [0x1b, b'[', ..] => parse_csi(bytes),
+        [0x1b, b'O', ..] => parse_ss3(bytes),
+        // Alt is sent as an escape byte before the key
+        [0x1b, rest @ ..] => match parse_event(rest) {
+            Parsed::Event(event, length) => {
+                event.key_event().map_or(Parsed::Skip(length + 1), |event| {
+                    Parsed::Event(
+                        InputEvent::Key(event.with_modifiers(KeyModifiers::ALT)),
+                        length + 1,
+                    )
+                })
+            }
+            Parsed::Skip(length) => Parsed::Skip(length + 1),
+            Parsed::Incomplete => Parsed::Incomplete,
+        },
+        [byte, ..] => parse_byte(bytes, *byte),
+    }
+}
+
+fn parse_byte(bytes: &[u8], byte: u8) -> Parsed {
+    let control = |c: u8| key_event(Key::Char(char::from(c)), KeyModifiers::CONTROL, 1);
+
+    match byte {
+        b'\r' | b'\n' => key_event(Key::Enter, KeyModifiers::NONE, 1),
+        b'\t' => key_event(Key::Tab, KeyModifiers::NONE, 1),
+        0x08 | 0x7f => key_event(Key::Backspace, KeyModifiers::NONE, 1),
+        0x00 => control(b' '),
+        0x01..=0x1a => control(byte - 1 + b'a'),
+        0x1c..=0x1f => control(byte + 0x40),
+        _ => parse_utf8(bytes),
+    }
+}
+
+fn parse_utf8(bytes: &[u8]) -> Parsed {
+    let length = match bytes[0] {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => return Parsed::Skip(1),
+    };
+    if bytes.len() < length {
+        return Parsed::Incomplete;
+    }
+
+    std::str::from_utf8(&bytes[..length])
+        .ok()
+        .and_then(|s| s.chars().next())
+        .map_or(Parsed::Skip(1), |c| {
+            key_event(Key::Char(c), KeyModifiers::NONE, length)
+        })
+}
+
+/// Parse a Control Sequence Introducer sequence, made up of `\x1b[`, optional numeric parameters separated by `;` and a final byte
+fn parse_csi(bytes: &[u8]) -> Parsed {
+    // Everything up to the final byte should be parameter or intermediate bytes
+    let Some(final_index) = bytes[2..]
+        .iter()
+        .position(|b| !(0x20..=0x3f).contains(b))
+        .map(|i| i + 2)
+    else {
+        return if bytes.len() > MAX_SEQUENCE_LENGTH {
+            Parsed::Skip(1)
+        } else {
+            Parsed::Incomplete
+        };
+    };
+    if !(0x40..=0x7e).contains(&bytes[final_index]) {
+        // The sequence was interrupted, for example by the start of another escape sequence
+        return Parsed::Skip(final_index);
+    }
+    let length = final_index + 1;
+
+    let Ok(parameters) = std::str::from_utf8(&bytes[2..final_index]) else {
+        return Parsed::Skip(length);
+    };
+    if let Some(parameters) = parameters.strip_prefix('<') {
+        return parse_sgr_mouse(parameters, bytes[final_index], length);
+    }
+    let parameters: Vec<u16> = parameters
+        .split(';')
+        .map(|p| p.parse().unwrap_or(1))
+        .collect();
+    let modifiers = KeyModifiers::from_parameter(parameters.get(1).copied().unwrap_or(1));
+
+    let key = match bytes[final_index] {
+        b'~' => match parameters[0] {
+            1 | 7 => Key::Home,
+            2 => Key::Insert,
+            3 => Key::Delete,
+            4 | 8 => Key::End,
+            5 => Key::PageUp,
+            6 => Key::PageDown,
+            n @ 11..=15 => Key::F(n as u8 - 10),
+            n @ 17..=21 => Key::F(n as u8 - 11),
+            n @ 23..=24 => Key::F(n as u8 - 12),
+            _ => return Parsed::Skip(length),
+        },
+        b'Z' => return key_event(Key::Tab, modifiers | KeyModifiers::SHIFT, length),
+        final_byte => match final_key(final_byte) {
+            Some(key) => key,
+            None => return Parsed::Skip(length),
+        },
+    };
+
+    key_event(key, modifiers, length)
+}
+
+/// Parse the parameters of an SGR (1006) mouse report, `\x1b[<{code};{x};{y}M`, which ends with `m` instead if a button was released
+fn parse_sgr_mouse(parameters: &str, final_byte: u8, length: usize) -> Parsed {
+    let mut values = parameters.split(';').map(str::parse);
+    let (Some(Ok(code)), Some(Ok(x)), Some(Ok(y))) = (values.next(), values.next(), values.next())
+    else {
+        return Parsed::Skip(length);
+    };
+
+    let event = matches!(final_byte, b'M' | b'm')
+        .then(|| MouseEvent::from_sgr(code, x, y, final_byte == b'm'))
+        .flatten();
+    event.map_or(Parsed::Skip(length), |event| {
+        Parsed::Event(InputEvent::Mouse(event), length)
+    })
+}
+
+/// Parse a Single Shift Three sequence, made up of `\x1bO` and one more byte, which some terminals send for the arrow keys and `F1` to `F4`
+fn parse_ss3(bytes: &[u8]) -> Parsed {
+    bytes.get(2).map_or(Parsed::Incomplete, |final_byte| {
+        final_key(*final_byte).map_or(Parsed::Skip(3), |key| key_event(key, KeyModifiers::NONE, 3))
+    })
+}
+
+/// Return the key identified by the final byte of a CSI or SS3 sequence
+const fn final_key(final_byte: u8) -> Option<Key> {
+    Some(match final_byte {
+        b'A' => Key::Up,
+        b'B' => Key::Down,
+        b'C' => Key::Right,
+        b'D' => Key::Left,
+        b'H' => Key::Home,
+        b'F' => Key::End,
+        b'P' => Key::F(1),
+        b'Q' => Key::F(2),
+        b'R' => Key::F(3),
+        b'S' => Key::F(4),
+        _ => return None,
+    })
+}
@@ -1,3 +1,4 @@
+use super::{Material, TexCoord};
 use crate::core::ColChar;
 
 /// An alias to [`DVec3`](glam::DVec3), a three-dimensional vector of `f64` values
@@ -12,6 +13,10 @@ pub struct Face {
     pub v_indices: Vec<usize>,
     /// The desired appearance of the face when rendered
     pub fill_char: ColChar,
+    /// The texture coordinates of each vertex of the face, in the same order as `v_indices`. If the face has these and its [`Mesh3D`](super::Mesh3D) has a [`Texture`](super::Texture), the texture is mapped across the face in place of `fill_char` when rendered with [`DisplayMode::Solid`](crate::view3d::DisplayMode::Solid) or [`DisplayMode::Illuminated`](crate::view3d::DisplayMode::Illuminated)
+    pub uvs: Option<Vec<TexCoord>>,
+    /// The [`Material`] of the face. If this is `None`, the face uses its [`Mesh3D`](super::Mesh3D)'s `material`
+    pub material: Option<Material>,
 }
 
 impl Face {
@@ -21,9 +26,25 @@ impl Face {
         Self {
             v_indices,
             fill_char,
+            uvs: None,
+            material: None,
         }
     }
 
+    /// Return the `Face` with an updated `uvs` property. Consumes the original `Face`
+    #[must_use]
+    pub fn with_uvs(mut self, uvs: Vec<TexCoord>) -> Self {
+        self.uvs = Some(uvs);
+        self
+    }
+
+    /// Return the `Face` with an updated `material` property. Consumes the original `Face`
+    #[must_use]
+    pub const fn with_material(mut self, material: Material) -> Self {
+        self.material = Some(material);
+        self
+    }
+
     /// Return a vector with the elements found at the vertex indices of the given slice
     ///
     /// # Errors
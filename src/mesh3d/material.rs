@@ -0,0 +1,71 @@
+/// Describes how a surface responds to light in [`DisplayMode::Illuminated`](crate::view3d::DisplayMode::Illuminated)
+///
+/// A `Material` can be set for a whole [`Mesh3D`](super::Mesh3D) with its `material`, or for a single [`Face`](super::Face). The light falling on each point is `emissive + diffuse * (diffuse light) + specular * (specular highlights)`, where the specular highlights are calculated with the Blinn-Phong model from the position of the camera. The default `Material` is fully diffuse with no highlights or emission
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::mesh3d::{Material, Mesh3D};
+/// let shiny_cube = Mesh3D::default_cube().with_material(
+///     Material::new()
+///         .with_specular(0.8)
+///         .with_shininess(64.0),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Material {
+    /// How much of the light falling on the surface is scattered evenly in every direction. Defaults to `1.0`
+    pub diffuse: f64,
+    /// The strength of the specular highlights reflected towards the camera. Defaults to `0.0`, so there are no highlights
+    pub specular: f64,
+    /// How concentrated the specular highlights are. Higher values make smaller, sharper highlights, like those on polished surfaces. Defaults to `32.0`
+    pub shininess: f64,
+    /// Light given off by the surface itself, added regardless of the lights in the scene. Defaults to `0.0`
+    pub emissive: f64,
+}
+
+impl Material {
+    /// Create a fully diffuse `Material` with no specular highlights or emission
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            diffuse: 1.0,
+            specular: 0.0,
+            shininess: 32.0,
+            emissive: 0.0,
+        }
+    }
+
+    /// Return the `Material` with an updated `diffuse` property. Consumes the original `Material`
+    #[must_use]
+    pub const fn with_diffuse(mut self, diffuse: f64) -> Self {
+        self.diffuse = diffuse;
+        self
+    }
+
+    /// Return the `Material` with an updated `specular` property. Consumes the original `Material`
+    #[must_use]
+    pub const fn with_specular(mut self, specular: f64) -> Self {
+        self.specular = specular;
+        self
+    }
+
+    /// Return the `Material` with an updated `shininess` property. Consumes the original `Material`
+    #[must_use]
+    pub const fn with_shininess(mut self, shininess: f64) -> Self {
+        self.shininess = shininess;
+        self
+    }
+
+    /// Return the `Material` with an updated `emissive` property. Consumes the original `Material`
+    #[must_use]
+    pub const fn with_emissive(mut self, emissive: f64) -> Self {
+        self.emissive = emissive;
+        self
+    }
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Self::new()
+    }
+}
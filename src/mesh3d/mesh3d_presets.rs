@@ -3,12 +3,20 @@
 use crate::core::{ColChar, Modifier};
 use std::f64::consts::TAU;
 
-use super::{Face, Mesh3D, Transform3D, Vec3D};
+use super::{Face, Mesh3D, TexCoord, Transform3D, Vec3D};
 
 impl Mesh3D {
-    /// The `gemini_engine` equivalent of Blender's default cube. Has sides of length 2
+    /// The `gemini_engine` equivalent of Blender's default cube. Has sides of length 2. Every face has `uvs` covering the whole of a [`Texture`](super::Texture)
     #[must_use]
     pub fn default_cube() -> Self {
+        let (tl, tr, bl, br) = (
+            TexCoord::new(0.0, 0.0),
+            TexCoord::new(1.0, 0.0),
+            TexCoord::new(0.0, 1.0),
+            TexCoord::new(1.0, 1.0),
+        );
+        let uvs = |a, b, c, d| vec![a, b, c, d];
+
         Self::new(
             vec![
                 Vec3D::new(1.0, 1.0, -1.0),
@@ -21,17 +29,23 @@ impl Mesh3D {
                 Vec3D::new(-1.0, -1.0, 1.0),
             ],
             vec![
-                Face::new(vec![2, 3, 1, 0], ColChar::SOLID.with_mod(Modifier::BLUE)),
-                Face::new(vec![4, 5, 7, 6], ColChar::SOLID.with_mod(Modifier::BLUE)),
-                Face::new(vec![1, 3, 7, 5], ColChar::SOLID.with_mod(Modifier::None)),
-                Face::new(vec![4, 6, 2, 0], ColChar::SOLID.with_mod(Modifier::None)),
-                Face::new(vec![6, 7, 3, 2], ColChar::SOLID.with_mod(Modifier::RED)),
-                Face::new(vec![0, 1, 5, 4], ColChar::SOLID.with_mod(Modifier::RED)),
+                Face::new(vec![2, 3, 1, 0], ColChar::SOLID.with_mod(Modifier::BLUE))
+                    .with_uvs(uvs(tr, tl, bl, br)),
+                Face::new(vec![4, 5, 7, 6], ColChar::SOLID.with_mod(Modifier::BLUE))
+                    .with_uvs(uvs(bl, br, tr, tl)),
+                Face::new(vec![1, 3, 7, 5], ColChar::SOLID.with_mod(Modifier::None))
+                    .with_uvs(uvs(br, tr, tl, bl)),
+                Face::new(vec![4, 6, 2, 0], ColChar::SOLID.with_mod(Modifier::None))
+                    .with_uvs(uvs(br, tr, tl, bl)),
+                Face::new(vec![6, 7, 3, 2], ColChar::SOLID.with_mod(Modifier::RED))
+                    .with_uvs(uvs(tr, tl, bl, br)),
+                Face::new(vec![0, 1, 5, 4], ColChar::SOLID.with_mod(Modifier::RED))
+                    .with_uvs(uvs(tr, tl, bl, br)),
             ],
         )
     }
 
-    /// Create a torus (donut shape)
+    /// Create a torus (donut shape). The `uvs` of its faces wrap a [`Texture`](super::Texture) once around the torus horizontally and once around its tube vertically, and its vertices have exact `normals` for smooth shading
     #[must_use]
     pub fn torus(
         outer_radius: f64,
@@ -40,6 +54,7 @@ impl Mesh3D {
         inner_segments: usize,
     ) -> Self {
         let mut vertices = vec![];
+        let mut normals = vec![];
         let mut faces = vec![];
 
         for outer_i in 0..outer_segments {
@@ -58,23 +73,41 @@ impl Mesh3D {
                     inner_angle.sin() * inner_radius,
                     0.0,
                 );
-                vertices.push(outer_point + outer_transform.transform_vector3(inner_point));
+                let offset = outer_transform.transform_vector3(inner_point);
+                vertices.push(outer_point + offset);
+                normals.push(offset.normalize());
 
                 let inc_outer_i = (outer_i + 1) % outer_segments;
                 let inc_inner_i = (inner_i + 1) % inner_segments;
-                faces.push(Face::new(
-                    vec![
-                        inc_outer_i * inner_segments + inner_i,
-                        inc_outer_i * inner_segments + inc_inner_i,
-                        outer_i * inner_segments + inc_inner_i,
-                        outer_i * inner_segments + inner_i,
-                    ],
-                    ColChar::SOLID,
-                ));
+                let (u0, u1) = (
+                    outer_i as f64 / outer_segments as f64,
+                    (outer_i + 1) as f64 / outer_segments as f64,
+                );
+                let (v0, v1) = (
+                    inner_i as f64 / inner_segments as f64,
+                    (inner_i + 1) as f64 / inner_segments as f64,
+                );
+                faces.push(
+                    Face::new(
+                        vec![
+                            inc_outer_i * inner_segments + inner_i,
+                            inc_outer_i * inner_segments + inc_inner_i,
+                            outer_i * inner_segments + inc_inner_i,
+                            outer_i * inner_segments + inner_i,
+                        ],
+                        ColChar::SOLID,
+                    )
+                    .with_uvs(vec![
+                        TexCoord::new(u1, v0),
+                        TexCoord::new(u1, v1),
+                        TexCoord::new(u0, v1),
+                        TexCoord::new(u0, v0),
+                    ]),
+                );
             }
         }
 
-        Self::new(vertices, faces)
+        Self::new(vertices, faces).with_normals(normals)
     }
 
     /// A gimbal to help you orient in `gemini_engine`'s 3D space. The orientation is as follows (from the default [`Viewport`](crate::view3d::Viewport))
@@ -1,9 +1,13 @@
 //! This module contains the [`Mesh3D`], which stores 3D objects as vertices and index faces
 
 mod components;
+mod material;
 mod mesh3d_presets;
+mod texture;
 
 pub use components::{Face, Transform3D, Vec3D};
+pub use material::Material;
+pub use texture::{TexCoord, Texture};
 
 /// A 3D mesh made up of vertices, faces made of indices into `vertices`, and a transformation.
 #[derive(Debug, Clone)]
@@ -14,6 +18,12 @@ pub struct Mesh3D {
     pub vertices: Vec<Vec3D>,
     /// A vector of [`Face`]s of indexes into `vertices`
     pub faces: Vec<Face>,
+    /// The normal of each vertex, pointing out of the mesh, used for smooth shading in [`DisplayMode::Illuminated`](crate::view3d::DisplayMode::Illuminated). If this is `None`, they are calculated with [`Mesh3D::calculate_normals`] when needed
+    pub normals: Option<Vec<Vec3D>>,
+    /// The [`Texture`] mapped onto any faces with `uvs`
+    pub texture: Option<Texture>,
+    /// The [`Material`] used by faces that don't have their own
+    pub material: Material,
 }
 
 impl Mesh3D {
@@ -24,6 +34,9 @@ impl Mesh3D {
             transform: Transform3D::IDENTITY,
             vertices,
             faces,
+            normals: None,
+            texture: None,
+            material: Material::new(),
         }
     }
 
@@ -33,4 +46,72 @@ impl Mesh3D {
         self.transform = transform;
         self
     }
+
+    /// Return the `Mesh3D` with an updated `normals` property. Consumes the original `Mesh3D`
+    #[must_use]
+    pub fn with_normals(mut self, normals: Vec<Vec3D>) -> Self {
+        self.normals = Some(normals);
+        self
+    }
+
+    /// Calculate a normal for every vertex by averaging the normals of the faces around it, weighted by their area. The normals point out of the mesh, as long as the faces are wound as described in [`Face`]. Vertices that aren't part of any face with at least 3 vertices get a normal of [`Vec3D::ZERO`]
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::mesh3d::{Mesh3D, Vec3D};
+    /// let normals = Mesh3D::default_cube().calculate_normals();
+    /// // Each corner of the cube points diagonally away from its centre
+    /// assert!(normals[1].abs_diff_eq(Vec3D::ONE.normalize(), 1e-9));
+    /// ```
+    #[must_use]
+    pub fn calculate_normals(&self) -> Vec<Vec3D> {
+        let mut normals = vec![Vec3D::ZERO; self.vertices.len()];
+        for face in &self.faces {
+            let Ok(vertices) = face.index_into(&self.vertices) else {
+                continue;
+            };
+
+            // Faces are wound clockwise, so the sum of each triangle's cross product points into the mesh
+            let normal: Vec3D = (1..vertices.len().saturating_sub(1))
+                .map(|i| (vertices[0] - vertices[i]).cross(vertices[0] - vertices[i + 1]))
+                .sum();
+            for &i in &face.v_indices {
+                normals[i] -= normal;
+            }
+        }
+
+        normals.into_iter().map(Vec3D::normalize_or_zero).collect()
+    }
+
+    /// Return the `Mesh3D` with an updated `material` property. Consumes the original `Mesh3D`
+    #[must_use]
+    pub const fn with_material(mut self, material: Material) -> Self {
+        self.material = material;
+        self
+    }
+
+    /// Return the `Mesh3D` with an updated `texture` property. Consumes the original `Mesh3D`
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::{mesh3d::{Mesh3D, Texture, Transform3D, Vec3D}, view3d::Viewport, view::View, core::{ColChar, Modifier, Vec2D}};
+    /// let mut view = View::new(40, 20, ColChar::EMPTY);
+    /// let mut viewport = Viewport::new(
+    ///     Transform3D::look_at_lh(Vec3D::new(0.0, -1.5, 4.3), Vec3D::ZERO, Vec3D::Y),
+    ///     80.0,
+    ///     view.center(),
+    /// );
+    /// // Every face of the default cube has `uvs`
+    /// let texture = Texture::filled(4, 4, ColChar::new('#', Modifier::RED));
+    /// viewport.objects.push(Mesh3D::default_cube().with_texture(texture));
+    ///
+    /// view.draw(&viewport);
+    /// assert!(view.to_plain_text().contains('#'));
+    /// assert!(!view.to_plain_text().contains('█'));
+    /// ```
+    #[must_use]
+    pub fn with_texture(mut self, texture: Texture) -> Self {
+        self.texture = Some(texture);
+        self
+    }
 }
@@ -0,0 +1,107 @@
+use crate::{
+    ascii::{Sprite, TextAlign2D, display_width},
+    core::{ColChar, Vec2D},
+    view::View,
+};
+
+/// An alias to [`DVec2`](glam::DVec2), a two-dimensional vector of `f64` values used as a texture coordinate. `x` (u) runs from `0.0` at the left edge of a [`Texture`] to `1.0` at the right edge, and `y` (v) from `0.0` at the top edge to `1.0` at the bottom edge
+pub type TexCoord = glam::DVec2;
+
+/// A 2D grid of [`ColChar`]s that can be mapped onto the faces of a [`Mesh3D`](super::Mesh3D) using each [`Face`](super::Face)'s `uvs`
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::{mesh3d::{TexCoord, Texture}, ascii::Sprite, core::{ColChar, Modifier, Vec2D}};
+/// let texture = Texture::from_sprite(&Sprite::new(Vec2D::ZERO, "ab\ncd", Modifier::None));
+/// assert_eq!(texture.size(), Vec2D::new(2, 2));
+/// assert_eq!(texture.sample(TexCoord::new(0.75, 0.25)).text_char, 'b');
+/// // The edges at 1.0 are part of the last texels
+/// assert_eq!(texture.sample(TexCoord::new(1.0, 1.0)).text_char, 'd');
+/// // Coordinates outside of 0.0 to 1.0 repeat the texture
+/// assert_eq!(texture.sample(TexCoord::new(1.25, -0.25)).text_char, 'c');
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Texture {
+    width: usize,
+    height: usize,
+    texels: Vec<ColChar>,
+}
+
+impl Texture {
+    /// Create a `Texture` from its `texels`, from left to right and top to bottom
+    ///
+    /// # Panics
+    /// Panics if the number of `texels` isn't `width * height`, or if the `Texture` would be empty
+    #[must_use]
+    pub fn new(width: usize, height: usize, texels: Vec<ColChar>) -> Self {
+        assert!(
+            width > 0 && height > 0,
+            "Texture was created with a width or height of 0"
+        );
+        assert_eq!(
+            texels.len(),
+            width * height,
+            "Texture was created with the wrong number of texels for its size"
+        );
+
+        Self {
+            width,
+            height,
+            texels,
+        }
+    }
+
+    /// Create a `Texture` filled with a single [`ColChar`]
+    #[must_use]
+    pub fn filled(width: usize, height: usize, texel: ColChar) -> Self {
+        Self::new(width, height, vec![texel; width * height])
+    }
+
+    /// Create a `Texture` from the contents of a [`View`]
+    #[must_use]
+    pub fn from_view(view: &View) -> Self {
+        let texels = view.rows().flatten().copied().collect();
+        Self::new(view.width, view.height, texels)
+    }
+
+    /// Create a `Texture` from a [`Sprite`]'s ASCII art, ignoring its `pos` and `align`. Cells left empty by the `Sprite`, such as spaces and the ends of short lines, are [`ColChar::EMPTY`]
+    #[must_use]
+    pub fn from_sprite(sprite: &Sprite) -> Self {
+        let mut sprite = sprite.clone();
+        sprite.pos = Vec2D::ZERO;
+        sprite.align = TextAlign2D::default();
+
+        let width = sprite.texture.lines().map(display_width).max().unwrap_or(0);
+        let height = sprite.texture.lines().count();
+
+        let mut view = View::new(width.max(1), height.max(1), ColChar::EMPTY);
+        view.draw(&sprite);
+        Self::from_view(&view)
+    }
+
+    /// Return the width and height of the `Texture` in texels
+    #[must_use]
+    pub const fn size(&self) -> Vec2D {
+        Vec2D::new(self.width as i64, self.height as i64)
+    }
+
+    /// Return the texels of the `Texture`, from left to right and top to bottom
+    #[must_use]
+    pub fn texels(&self) -> &[ColChar] {
+        &self.texels
+    }
+
+    /// Return the texel at the given texture coordinate. Coordinates outside of `0.0` to `1.0` wrap around, repeating the texture, and a coordinate of `1.0` samples the last texel rather than wrapping back to the first
+    #[must_use]
+    pub fn sample(&self, uv: TexCoord) -> ColChar {
+        let wrap = |coord: f64, size: usize| {
+            // A whole number above 0.0 is the far edge of the texture, so faces mapped from 0.0 to 1.0 don't show a seam of the first texel
+            let fract = match coord.rem_euclid(1.0) {
+                0.0 if coord > 0.0 => 1.0,
+                fract => fract,
+            };
+            ((fract * size as f64) as usize).min(size - 1)
+        };
+        self.texels[wrap(uv.y, self.height) * self.width + wrap(uv.x, self.width)]
+    }
+}
@@ -39,17 +39,17 @@ impl CanDraw for Line {
             if e2 >= dy {
                 if x == x1 {
                     break;
-                };
+                }
                 error += dy;
                 x += sx;
-            };
+            }
             if e2 <= dx {
                 if y == y1 {
                     break;
-                };
+                }
                 error += dx;
                 y += sy;
-            };
+            }
         }
     }
 }
@@ -0,0 +1,226 @@
+use crate::{
+    core::{Canvas, ColChar, Colour, Modifier, Style, Vec2D, char_width},
+    view::View,
+};
+
+/// Writes terminal output to a [`View`] as a terminal would display it. Only the escape codes written by [`View::display_render`] are supported: cursor movement, clearing the screen or line and SGR styles. Anything else is ignored
+#[derive(Debug, Clone, Default)]
+pub struct Emulator {
+    cursor: Vec2D,
+    style: Style,
+    /// An escape sequence that was split between calls to [`Emulator::write`]
+    pending: String,
+}
+
+impl Emulator {
+    pub fn write(&mut self, view: &mut View, output: &str) {
+        let output = std::mem::take(&mut self.pending) + output;
+        let mut chars = output.char_indices().peekable();
+
+        while let Some((start, c)) = chars.next() {
+            match c {
+                '\x1b' => {
+                    if chars.next_if(|(_, c)| *c == '[').is_none() {
+                        if chars.peek().is_none() {
+                            self.pending = output[start..].to_string();
+                        }
+                        continue;
+                    }
+                    let mut parameters = String::new();
+                    let final_char = loop {
+                        match chars.next() {
+                            Some((_, c @ '@'..='~')) => break Some(c),
+                            Some((_, c)) => parameters.push(c),
+                            None => break None,
+                        }
+                    };
+                    match final_char {
+                        Some(final_char) => self.apply_csi(view, &parameters, final_char),
+                        None => self.pending = output[start..].to_string(),
+                    }
+                }
+                '\r' => self.cursor.x = 0,
+                '\n' => self.line_feed(view),
+                '\x08' => self.cursor.x = (self.cursor.x - 1).max(0),
+                c if c.is_control() => {}
+                c => self.write_char(view, c),
+            }
+        }
+    }
+
+    /// Write a printable character at the cursor. Wide characters take up two cells, and combining characters are attached to the cell before the cursor
+    fn write_char(&mut self, view: &mut View, c: char) {
+        let modifier = self.modifier();
+        match char_width(c) {
+            0 => {
+                let previous = self.cursor - Vec2D::new(1, 0);
+                if let Some(pixel) = view.get(previous)
+                    && pixel.combining.is_none()
+                {
+                    view.plot(previous, pixel.with_combining(c));
+                }
+            }
+            2 => {
+                view.plot(self.cursor, ColChar::new(c, modifier));
+                view.plot(
+                    self.cursor + Vec2D::new(1, 0),
+                    ColChar::CONTINUATION.with_mod(modifier),
+                );
+                self.cursor.x += 2;
+            }
+            _ => {
+                view.plot(self.cursor, ColChar::new(c, modifier));
+                self.cursor.x += 1;
+            }
+        }
+    }
+
+    const fn modifier(&self) -> Modifier {
+        if self.style.is_empty() {
+            Modifier::None
+        } else {
+            Modifier::Style(self.style)
+        }
+    }
+
+    fn line_feed(&mut self, view: &mut View) {
+        if self.cursor.y + 1 < view.height as i64 {
+            self.cursor.y += 1;
+            return;
+        }
+
+        // Scroll everything up by a row
+        for y in 1..view.height as i64 {
+            for x in 0..view.width as i64 {
+                if let Some(c) = view.get(Vec2D::new(x, y)) {
+                    view.plot(Vec2D::new(x, y - 1), c);
+                }
+            }
+        }
+        let last_row = view.height as i64 - 1;
+        clear_cells(
+            view,
+            Vec2D::new(0, last_row),
+            Vec2D::new(view.width as i64 - 1, last_row),
+        );
+    }
+
+    fn apply_csi(&mut self, view: &mut View, parameters: &str, final_char: char) {
+        // Private modes such as `?25l` (hide cursor) don't affect what's on screen
+        if parameters.starts_with('?') {
+            return;
+        }
+        let parameters: Vec<i64> = parameters
+            .split(';')
+            .map(|p| p.parse().unwrap_or(0))
+            .collect();
+        let parameter = |i: usize| parameters.get(i).copied().unwrap_or(0);
+        let count = parameter(0).max(1);
+
+        let size = view.size();
+        match final_char {
+            'H' | 'f' => {
+                self.cursor = Vec2D::new(parameter(1).max(1) - 1, parameter(0).max(1) - 1);
+            }
+            'A' => self.cursor.y = self.cursor.y.saturating_sub(count),
+            'B' => self.cursor.y = self.cursor.y.saturating_add(count),
+            'C' => self.cursor.x = self.cursor.x.saturating_add(count),
+            'D' => self.cursor.x = self.cursor.x.saturating_sub(count),
+            'G' => self.cursor.x = count - 1,
+            'J' => {
+                let end_of_screen = size - 1;
+                match parameter(0) {
+                    0 => clear_cells(view, self.cursor, end_of_screen),
+                    1 => clear_cells(view, Vec2D::ZERO, self.cursor),
+                    _ => clear_cells(view, Vec2D::ZERO, end_of_screen),
+                }
+            }
+            'K' => {
+                let start = Vec2D::new(0, self.cursor.y);
+                let end = Vec2D::new(size.x - 1, self.cursor.y);
+                match parameter(0) {
+                    0 => clear_cells(view, self.cursor, end),
+                    1 => clear_cells(view, start, self.cursor),
+                    _ => clear_cells(view, start, end),
+                }
+            }
+            'm' => self.apply_sgr(&parameters),
+            _ => {}
+        }
+        self.cursor = self.cursor.clamp(Vec2D::ZERO, (size - 1).max(Vec2D::ZERO));
+    }
+
+    fn apply_sgr(&mut self, parameters: &[i64]) {
+        let mut codes = parameters.iter().map(|&p| p.clamp(0, 255) as u8);
+        while let Some(code) = codes.next() {
+            match code {
+                0 => self.style = Style::new(),
+                38 | 48 => {
+                    let colour = match codes.next() {
+                        Some(2) => {
+                            let mut channel = || codes.next().unwrap_or(0);
+                            Some(Colour::rgb(channel(), channel(), channel()))
+                        }
+                        Some(5) => codes.next().map(Colour::from_ansi_256),
+                        _ => None,
+                    };
+                    if code == 38 {
+                        self.style.foreground = colour;
+                    } else {
+                        self.style.background = colour;
+                    }
+                }
+                39 => self.style.foreground = None,
+                49 => self.style.background = None,
+                // Other colour and attribute codes, such as the ones written by `Modifier::Coded`
+                code => {
+                    let style = Style::from(Modifier::Coded(code));
+                    self.style.foreground = style.foreground.or(self.style.foreground);
+                    self.style.background = style.background.or(self.style.background);
+                    self.style.attributes |= style.attributes;
+                }
+            }
+        }
+    }
+}
+
+/// Clear every cell from `start` to `end` inclusive, reading left to right and top to bottom
+fn clear_cells(view: &mut View, start: Vec2D, end: Vec2D) {
+    let width = view.width as i64;
+    if width == 0 {
+        return;
+    }
+    for i in (start.y * width + start.x)..=(end.y * width + end.x) {
+        view.plot(Vec2D::new(i % width, i / width), ColChar::EMPTY);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Emulator;
+    use crate::{core::ColChar, view::View};
+
+    #[test]
+    fn huge_cursor_moves_stop_at_the_edge() {
+        let mut view = View::new(3, 3, ColChar::EMPTY);
+        let mut emulator = Emulator::default();
+
+        emulator.write(
+            &mut view,
+            "\x1b[2;1H\x1b[9223372036854775807B\x1b[9223372036854775807Ca",
+        );
+        assert_eq!(view.to_plain_text(), "   \n   \n  a\n");
+
+        emulator.write(
+            &mut view,
+            "\x1b[9223372036854775807A\x1b[9223372036854775807Db",
+        );
+        assert_eq!(view.to_plain_text(), "b  \n   \n  a\n");
+
+        emulator.write(
+            &mut view,
+            "\x1b[-9223372036854775808;-9223372036854775808Hc",
+        );
+        assert_eq!(view.to_plain_text(), "c  \n   \n  a\n");
+    }
+}
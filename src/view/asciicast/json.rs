@@ -0,0 +1,285 @@
+//! Just enough JSON to read and write asciicast files
+
+use std::{fmt::Write, iter::Peekable, str::Chars};
+
+/// A parsed JSON value
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Self>),
+    Object(Vec<(String, Self)>),
+}
+
+impl Json {
+    /// Return the value of the key if this is an object containing it
+    pub fn get(&self, key: &str) -> Option<&Self> {
+        match self {
+            Self::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub const fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+/// Return the string as a quoted JSON string
+pub fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", u32::from(c));
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Parse a single JSON value, which must make up the whole string apart from surrounding whitespace
+pub fn parse(s: &str) -> Result<Json, String> {
+    let mut chars = s.chars().peekable();
+    let value = parse_value(&mut chars)?;
+    skip_whitespace(&mut chars);
+    chars.next().map_or(Ok(value), |c| {
+        Err(format!("Unexpected `{c}` after JSON value"))
+    })
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+fn expect(chars: &mut Peekable<Chars>, expected: char) -> Result<(), String> {
+    skip_whitespace(chars);
+    match chars.next() {
+        Some(c) if c == expected => Ok(()),
+        Some(c) => Err(format!("Expected `{expected}`, found `{c}`")),
+        None => Err(format!("Expected `{expected}`, found the end of the input")),
+    }
+}
+
+fn parse_value(chars: &mut Peekable<Chars>) -> Result<Json, String> {
+    skip_whitespace(chars);
+    match chars.peek() {
+        Some('"') => parse_string(chars).map(Json::String),
+        Some('[') => {
+            chars.next();
+            let mut values = vec![];
+            skip_whitespace(chars);
+            if chars.next_if_eq(&']').is_none() {
+                loop {
+                    values.push(parse_value(chars)?);
+                    skip_whitespace(chars);
+                    match chars.next() {
+                        Some(',') => {}
+                        Some(']') => break,
+                        _ => return Err(String::from("Expected `,` or `]` in array")),
+                    }
+                }
+            }
+            Ok(Json::Array(values))
+        }
+        Some('{') => {
+            chars.next();
+            let mut entries = vec![];
+            skip_whitespace(chars);
+            if chars.next_if_eq(&'}').is_none() {
+                loop {
+                    skip_whitespace(chars);
+                    let key = parse_string(chars)?;
+                    expect(chars, ':')?;
+                    entries.push((key, parse_value(chars)?));
+                    skip_whitespace(chars);
+                    match chars.next() {
+                        Some(',') => {}
+                        Some('}') => break,
+                        _ => return Err(String::from("Expected `,` or `}` in object")),
+                    }
+                }
+            }
+            Ok(Json::Object(entries))
+        }
+        Some(_) => {
+            let mut word = String::new();
+            while let Some(c) =
+                chars.next_if(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '.'))
+            {
+                word.push(c);
+            }
+            match word.as_str() {
+                "null" => Ok(Json::Null),
+                "true" => Ok(Json::Bool(true)),
+                "false" => Ok(Json::Bool(false)),
+                // Rust also parses words like `inf` and `NaN` as numbers, which JSON doesn't allow
+                number
+                    if number.starts_with(|c: char| c == '-' || c.is_ascii_digit())
+                        && number.chars().all(|c| {
+                            c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')
+                        }) =>
+                {
+                    number
+                        .parse()
+                        .map(Json::Number)
+                        .map_err(|_| format!("Invalid JSON number `{number}`"))
+                }
+                value => Err(format!("Invalid JSON value `{value}`")),
+            }
+        }
+        None => Err(String::from(
+            "Expected a JSON value, found the end of the input",
+        )),
+    }
+}
+
+fn parse_string(chars: &mut Peekable<Chars>) -> Result<String, String> {
+    expect(chars, '"')?;
+    let mut s = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(s),
+            Some('\\') => match chars.next() {
+                Some('n') => s.push('\n'),
+                Some('r') => s.push('\r'),
+                Some('t') => s.push('\t'),
+                Some('b') => s.push('\u{8}'),
+                Some('f') => s.push('\u{c}'),
+                Some('u') => {
+                    let mut code = parse_hex_escape(chars)?;
+                    // Characters outside the basic multilingual plane are written as a surrogate pair
+                    if (0xd800..0xdc00).contains(&code) {
+                        let low = (chars.next_if_eq(&'\\').is_some()
+                            && chars.next_if_eq(&'u').is_some())
+                        .then(|| parse_hex_escape(chars))
+                        .transpose()?
+                        .filter(|low| (0xdc00..0xe000).contains(low))
+                        .ok_or_else(|| String::from("Unpaired surrogate in JSON string"))?;
+                        code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                    }
+                    s.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                }
+                Some(c) => s.push(c),
+                None => break,
+            },
+            Some(c) => s.push(c),
+            None => break,
+        }
+    }
+    Err(String::from("Unterminated JSON string"))
+}
+
+fn parse_hex_escape(chars: &mut Peekable<Chars>) -> Result<u32, String> {
+    let hex: String = chars.by_ref().take(4).collect();
+    if hex.len() == 4 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        u32::from_str_radix(&hex, 16).map_err(|_| format!("Invalid unicode escape `\\u{hex}`"))
+    } else {
+        Err(format!("Invalid unicode escape `\\u{hex}`"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Json, escape, parse};
+
+    #[test]
+    fn string_escapes() {
+        assert_eq!(
+            parse(r#""a\n\t\"\\\/\u00e9""#),
+            Ok(Json::String(String::from("a\n\t\"\\/é")))
+        );
+        assert_eq!(
+            parse(r#""\ud83e\udd80""#),
+            Ok(Json::String(String::from("🦀")))
+        );
+    }
+
+    #[test]
+    fn invalid_surrogates() {
+        assert!(parse(r#""\ud83e""#).is_err());
+        assert!(parse(r#""\ud83e\u0041""#).is_err());
+        assert!(parse(r#""\ud83e \udd80""#).is_err());
+    }
+
+    #[test]
+    fn escape_round_trips() {
+        let s = "\x1b[38;2;1;2;3m\"quoted\"\\\r\n\u{7f}中";
+        assert_eq!(parse(&escape(s)), Ok(Json::String(String::from(s))));
+    }
+
+    #[test]
+    fn nested_arrays() {
+        assert_eq!(
+            parse("[[1, [2, []]], [], \"x\"]"),
+            Ok(Json::Array(vec![
+                Json::Array(vec![
+                    Json::Number(1.0),
+                    Json::Array(vec![Json::Number(2.0), Json::Array(vec![])]),
+                ]),
+                Json::Array(vec![]),
+                Json::String(String::from("x")),
+            ]))
+        );
+    }
+
+    #[test]
+    fn objects() {
+        let object = parse(r#"{"version": 2, "env": {"TERM": "xterm"}, "ok": true}"#)
+            .expect("the object should parse");
+        assert_eq!(object.get("version").and_then(Json::as_f64), Some(2.0));
+        assert_eq!(
+            object.get("env").and_then(|env| env.get("TERM")),
+            Some(&Json::String(String::from("xterm")))
+        );
+        assert_eq!(object.get("ok"), Some(&Json::Bool(true)));
+        assert_eq!(object.get("missing"), None);
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse("1e3"), Ok(Json::Number(1000.0)));
+        assert_eq!(parse("-2.5E-2"), Ok(Json::Number(-0.025)));
+        assert_eq!(parse("0.123456"), Ok(Json::Number(0.123_456)));
+        assert_eq!(parse("1e400"), Ok(Json::Number(f64::INFINITY)));
+        assert!(parse("inf").is_err());
+        assert!(parse("NaN").is_err());
+        assert!(parse("1e").is_err());
+    }
+
+    #[test]
+    fn truncated_input() {
+        for input in [
+            "",
+            "[1, 2",
+            "[1,",
+            r#"{"a": "#,
+            r#"{"a""#,
+            r#""abc"#,
+            r#""\"#,
+            r#""\u12"#,
+            r#""\ud83e\ud"#,
+        ] {
+            assert!(parse(input).is_err(), "{input:?} should not parse");
+        }
+    }
+
+    #[test]
+    fn trailing_characters() {
+        assert!(parse("[1] [2]").is_err());
+        assert_eq!(parse("  [1]  "), Ok(Json::Array(vec![Json::Number(1.0)])));
+    }
+}
@@ -0,0 +1,318 @@
+use super::View;
+use crate::core::ColChar;
+use std::{
+    env,
+    io::{self, BufRead, Write},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+mod emulator;
+mod json;
+
+use emulator::Emulator;
+use json::Json;
+
+/// The largest width or height accepted from an asciicast file, so that a corrupt file can't make the [`View`] allocate huge amounts of memory
+const MAX_DIMENSION: usize = 1000;
+
+/// Records the frames rendered by a [`View`] to an [asciicast v2](https://docs.asciinema.org/manual/asciicast/v2/) file
+///
+/// The recording can be played back with `asciinema play`, embedded in a web page with the asciinema player or replayed with a [`CastPlayer`].
+/// Each frame is written as an output event as soon as it is rendered, so you may want to wrap `target` in a [`BufWriter`](std::io::BufWriter)
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::{view::{CastRecorder, View}, core::{ColChar, ColourDepth, Vec2D}, primitives::Pixel};
+/// let mut view = View::new(10, 3, ColChar::EMPTY).with_colour_depth(ColourDepth::TrueColour);
+/// let mut recorder = CastRecorder::new(Vec::new(), &mut view).unwrap();
+///
+/// for x in 0..10 {
+///     view.clear();
+///     view.draw(&Pixel::new(Vec2D::new(x, 1), ColChar::SOLID));
+///     recorder.record(&mut view).unwrap();
+/// }
+///
+/// let cast = String::from_utf8(recorder.into_inner()).unwrap();
+/// assert!(cast.starts_with(r#"{"version": 2, "width": 10, "height": 4"#));
+/// assert_eq!(cast.lines().count(), 11);
+/// ```
+#[derive(Debug)]
+pub struct CastRecorder<W: Write> {
+    target: W,
+    start: Instant,
+}
+
+impl<W: Write> CastRecorder<W> {
+    /// Create a new `CastRecorder`, writing the asciicast header to `target`. The recording is one row taller than the `View`, as rendering a `View` leaves the cursor on the row below it. The `View` will be fully repainted the next time it is rendered, so that the recording starts with a whole frame
+    ///
+    /// # Errors
+    /// Returns the `Result` from writing to `target`
+    pub fn new(mut target: W, view: &mut View) -> io::Result<Self> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let env = env::var("TERM")
+            .map(|term| format!(r#", "env": {{"TERM": {}}}"#, json::escape(&term)))
+            .unwrap_or_default();
+        writeln!(
+            target,
+            r#"{{"version": 2, "width": {}, "height": {}, "timestamp": {timestamp}{env}}}"#,
+            view.width,
+            view.height + 1,
+        )?;
+
+        view.force_full_repaint();
+
+        Ok(Self {
+            target,
+            start: Instant::now(),
+        })
+    }
+
+    /// Display the `View` with [`View::display_render`] and record the frame
+    ///
+    /// # Errors
+    /// Returns the `Result` from writing to `stdout` or `target`
+    pub fn display_render(&mut self, view: &mut View) -> io::Result<()> {
+        let mut stdout = io::stdout().lock();
+        view.prepare_stdout(&mut stdout)?;
+
+        let frame = Self::render_frame(view)?;
+        stdout.write_all(frame.as_bytes())?;
+        stdout.flush()?;
+
+        self.write_output(&frame)
+    }
+
+    /// Record the frame without displaying it
+    ///
+    /// # Errors
+    /// Returns the `Result` from writing to `target`
+    pub fn record(&mut self, view: &mut View) -> io::Result<()> {
+        let frame = Self::render_frame(view)?;
+        self.write_output(&frame)
+    }
+
+    /// Consume the `CastRecorder`, returning the target it was writing to
+    pub fn into_inner(self) -> W {
+        self.target
+    }
+
+    fn render_frame(view: &mut View) -> io::Result<String> {
+        let mut frame = Vec::new();
+        view.display_render_to(&mut frame)?;
+        String::from_utf8(frame).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn write_output(&mut self, output: &str) -> io::Result<()> {
+        writeln!(
+            self.target,
+            r#"[{:.6}, "o", {}]"#,
+            self.start.elapsed().as_secs_f64(),
+            json::escape(output),
+        )
+    }
+}
+
+/// Plays back an [asciicast v2](https://docs.asciinema.org/manual/asciicast/v2/) file, such as one recorded by a [`CastRecorder`], through a [`View`]
+///
+/// The recorded output is written to the `View` by a small terminal emulator, which supports the escape codes written by [`View::display_render`] (moving the cursor, clearing the screen and setting colours and text attributes). Recordings of other programs may not be displayed correctly. Any styled pixels are read back as [`Modifier::Style`](crate::core::Modifier::Style)s
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::{view::{CastPlayer, CastRecorder, View}, core::{ColChar, ColourDepth, Vec2D, Canvas}};
+/// let mut view = View::new(3, 1, ColChar::EMPTY).with_colour_depth(ColourDepth::TrueColour);
+/// let mut recorder = CastRecorder::new(Vec::new(), &mut view).unwrap();
+/// view.plot(Vec2D::new(1, 0), ColChar::SOLID);
+/// recorder.record(&mut view).unwrap();
+/// let cast = recorder.into_inner();
+///
+/// let mut player = CastPlayer::read(cast.as_slice()).unwrap();
+/// assert!(player.next_frame().is_some());
+/// assert_eq!(player.view().to_plain_text(), " █ \n   \n");
+/// assert!(player.next_frame().is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CastPlayer {
+    events: Vec<(Duration, CastEvent)>,
+    next_event: usize,
+    view: View,
+    emulator: Emulator,
+}
+
+impl CastPlayer {
+    /// Read an asciicast v2 file. Only output (`"o"`) and resize (`"r"`) events are used, and any others are skipped
+    ///
+    /// # Errors
+    /// Returns an error if reading from `source` fails, if it isn't a valid asciicast v2 file, or if its width or height (initially or after a resize) is greater than 1000
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::view::CastPlayer;
+    /// let huge = r#"{"version": 2, "width": 4000000000, "height": 24}"#;
+    /// assert!(CastPlayer::read(huge.as_bytes()).is_err());
+    ///
+    /// let never = "{\"version\": 2, \"width\": 80, \"height\": 24}\n[1e400, \"o\", \"hello\"]";
+    /// assert!(CastPlayer::read(never.as_bytes()).is_err());
+    /// ```
+    pub fn read(source: impl BufRead) -> io::Result<Self> {
+        let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+
+        let mut lines = source.lines();
+        let header =
+            json::parse(&lines.next().transpose()?.unwrap_or_default()).map_err(invalid)?;
+        if header.get("version").and_then(Json::as_f64) != Some(2.0) {
+            return Err(invalid(String::from(
+                "Only asciicast version 2 is supported",
+            )));
+        }
+        let dimension = |key: &str| {
+            let n = header
+                .get(key)
+                .and_then(Json::as_f64)
+                .ok_or_else(|| invalid(format!("The asciicast header has no `{key}`")))?;
+            if n.fract() == 0.0 && (0.0..=MAX_DIMENSION as f64).contains(&n) {
+                Ok(n as usize)
+            } else {
+                Err(invalid(format!(
+                    "The asciicast `{key}` must be a whole number no greater than {MAX_DIMENSION}, found `{n}`"
+                )))
+            }
+        };
+        let view = View::new(dimension("width")?, dimension("height")?, ColChar::EMPTY);
+
+        let mut events = vec![];
+        for line in lines {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let Json::Array(event) = json::parse(&line).map_err(invalid)? else {
+                return Err(invalid(format!("Invalid asciicast event `{line}`")));
+            };
+            if let [Json::Number(time), Json::String(code), Json::String(data)] = event.as_slice()
+                && matches!(code.as_str(), "o" | "r")
+            {
+                let event = if code == "r" {
+                    let (width, height) = parse_size(data).ok_or_else(|| {
+                        invalid(format!(
+                            "Invalid asciicast resize `{data}`. Sizes must be given as `{{width}}x{{height}}`, each no greater than {MAX_DIMENSION}"
+                        ))
+                    })?;
+                    CastEvent::Resize(width, height)
+                } else {
+                    CastEvent::Output(data.clone())
+                };
+                let time = Duration::try_from_secs_f64(time.max(0.0))
+                    .map_err(|_| invalid(format!("Invalid asciicast event time `{time}`")))?;
+                events.push((time, event));
+            }
+        }
+
+        Ok(Self {
+            events,
+            next_event: 0,
+            view,
+            emulator: Emulator::default(),
+        })
+    }
+
+    /// Return the [`View`] as of the last event played
+    #[must_use]
+    pub const fn view(&self) -> &View {
+        &self.view
+    }
+
+    /// Return the total length of the recording
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.events.last().map_or(Duration::ZERO, |(time, _)| *time)
+    }
+
+    /// Write the next event to the `View`, returning the time it happened at relative to the start of the recording, or `None` if there are no events left
+    pub fn next_frame(&mut self) -> Option<Duration> {
+        let (time, event) = self.events.get(self.next_event)?;
+        self.next_event += 1;
+
+        match event {
+            CastEvent::Output(data) => self.emulator.write(&mut self.view, data),
+            CastEvent::Resize(width, height) => {
+                self.view.width = *width;
+                self.view.height = *height;
+                self.view.clear();
+            }
+        }
+
+        Some(*time)
+    }
+
+    /// Play the rest of the recording in real time, rendering the `View` with [`View::display_render`] after each event
+    ///
+    /// # Errors
+    /// Returns the `Result` from writing to `stdout`
+    pub fn play(&mut self) -> io::Result<()> {
+        let start = Instant::now();
+        let offset = self
+            .events
+            .get(self.next_event)
+            .map_or(Duration::ZERO, |(time, _)| *time);
+
+        while let Some(time) = self.next_frame() {
+            if let Some(wait) = time.saturating_sub(offset).checked_sub(start.elapsed()) {
+                thread::sleep(wait);
+            }
+            self.view.display_render()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An event from an asciicast file that affects what is displayed
+#[derive(Debug, Clone)]
+enum CastEvent {
+    /// Output written to the terminal
+    Output(String),
+    /// The terminal being resized to a width and height no greater than [`MAX_DIMENSION`]
+    Resize(usize, usize),
+}
+
+/// Parse the size given by a resize event, `{width}x{height}`, if neither is greater than [`MAX_DIMENSION`]
+fn parse_size(size: &str) -> Option<(usize, usize)> {
+    let (width, height) = size.split_once('x')?;
+    let (width, height) = (width.parse().ok()?, height.parse().ok()?);
+    (width <= MAX_DIMENSION && height <= MAX_DIMENSION).then_some((width, height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CastPlayer;
+    use crate::core::Vec2D;
+
+    const HEADER: &str = r#"{"version": 2, "width": 4, "height": 2}"#;
+
+    #[test]
+    fn resize_events_resize_the_view() {
+        let cast = format!("{HEADER}\n[0.5, \"r\", \"6x3\"]");
+        let mut player = CastPlayer::read(cast.as_bytes()).expect("the cast should be valid");
+
+        assert!(player.next_frame().is_some());
+        assert_eq!(player.view().size(), Vec2D::new(6, 3));
+    }
+
+    #[test]
+    fn output_that_looks_like_a_resize_is_only_output() {
+        let cast = format!("{HEADER}\n[0.5, \"o\", \"\\u001b[8;200000;200000t\"]");
+        let mut player = CastPlayer::read(cast.as_bytes()).expect("the cast should be valid");
+
+        assert!(player.next_frame().is_some());
+        assert_eq!(player.view().size(), Vec2D::new(4, 2));
+    }
+
+    #[test]
+    fn oversized_resize_events_are_rejected() {
+        let cast = format!("{HEADER}\n[0.5, \"r\", \"200000x200000\"]");
+        assert!(CastPlayer::read(cast.as_bytes()).is_err());
+    }
+}
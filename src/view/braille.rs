@@ -0,0 +1,126 @@
+use super::WrappingMode;
+use crate::core::{CanDraw, Canvas, ColChar, Modifier, Vec2D};
+
+/// The blank braille pattern, to which the dot bits are added
+const BRAILLE_BASE: u32 = 0x2800;
+/// The width and height of the grid of dots in a single braille character
+const CELL_SIZE: Vec2D = Vec2D::new(2, 4);
+/// The bit for each dot in a braille character, indexed by `[x][y]`
+const DOT_BITS: [[u8; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+/// A [`Canvas`] with eight times the resolution, made by splitting every text character into a 2x4 grid of braille dots
+///
+/// Anything implementing [`CanDraw`] can be drawn to it, after which the `BrailleCanvas` itself can be drawn to a [`View`](super::View) (or any other `Canvas`) at the normal resolution. This works best for lines and wireframes, such as [`Line`](crate::primitives::Line) or [`DisplayMode::Wireframe`](crate::view3d::DisplayMode::Wireframe). The `text_char` of each plotted [`ColChar`] is ignored, and every cell takes the [`Modifier`] of the last dot plotted to it
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::{view::{View, BrailleCanvas}, core::{ColChar, Vec2D}, primitives::Line};
+/// let mut view = View::new(2, 1, ColChar::EMPTY);
+/// let mut canvas = BrailleCanvas::new(view.width * 2, view.height * 4);
+///
+/// canvas.draw(&Line::new(Vec2D::new(0, 0), Vec2D::new(3, 3), ColChar::SOLID));
+/// view.draw(&canvas);
+///
+/// assert_eq!(view.to_plain_text(), "⠑⢄\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct BrailleCanvas {
+    /// The width of the `BrailleCanvas`, in dots (two per text character). If modified, the canvas should be cleared to account for the new size
+    pub width: usize,
+    /// The height of the `BrailleCanvas`, in dots (four per row of text). If modified, the canvas should be cleared to account for the new size
+    pub height: usize,
+    /// Determine how to handle dots that are plotted outside the `BrailleCanvas`
+    pub wrapping_mode: WrappingMode,
+    /// The dot bits and modifier of every cell
+    cells: Vec<(u8, Modifier)>,
+}
+
+impl BrailleCanvas {
+    /// Create a new, empty `BrailleCanvas`. The size is given in dots, so a `BrailleCanvas` covering a whole `View` should be twice as wide and four times as tall as it
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        let mut canvas = Self {
+            width,
+            height,
+            wrapping_mode: WrappingMode::Ignore,
+            cells: vec![],
+        };
+        canvas.clear();
+
+        canvas
+    }
+
+    /// Return the `BrailleCanvas` with an updated `wrapping_mode` property. Consumes the original `BrailleCanvas`
+    #[must_use]
+    pub const fn with_wrapping_mode(mut self, wrapping_mode: WrappingMode) -> Self {
+        self.wrapping_mode = wrapping_mode;
+        self
+    }
+
+    /// Return the width and height of the `BrailleCanvas` in dots as a [`Vec2D`]
+    #[must_use]
+    pub const fn size(&self) -> Vec2D {
+        Vec2D::new(self.width as i64, self.height as i64)
+    }
+
+    /// Return the width and height of the `BrailleCanvas` in text characters as a [`Vec2D`]
+    #[must_use]
+    pub fn cell_size(&self) -> Vec2D {
+        (self.size() + CELL_SIZE - Vec2D::ONE) / CELL_SIZE
+    }
+
+    /// Return [`Vec2D`] coordinates of the centre of the `BrailleCanvas`
+    #[must_use]
+    pub fn center(&self) -> Vec2D {
+        self.size() / 2
+    }
+
+    /// Clear the `BrailleCanvas` of all dots
+    pub fn clear(&mut self) {
+        let cell_size = self.cell_size();
+        self.cells = vec![(0, Modifier::None); (cell_size.x * cell_size.y) as usize];
+    }
+
+    /// Draw a struct implementing [`CanDraw`] to the `BrailleCanvas`
+    #[inline]
+    pub fn draw(&mut self, element: &impl CanDraw) {
+        element.draw_to(self);
+    }
+}
+
+impl Canvas for BrailleCanvas {
+    /// Plot a dot to the `BrailleCanvas`. Only the [`Modifier`] of the [`ColChar`] is used
+    ///
+    /// # Panics
+    /// Will panic if the position is out of bounds of the `BrailleCanvas` and `wrapping_mode` is `WrappingMode::Panic`
+    fn plot(&mut self, pos: Vec2D, c: ColChar) {
+        if let Some(wrapped_pos) = self.wrapping_mode.handle_bounds(pos, self.size()) {
+            let cell_pos = wrapped_pos / CELL_SIZE;
+            let dot_pos = wrapped_pos % CELL_SIZE;
+
+            let i = (self.cell_size().x * cell_pos.y + cell_pos.x) as usize;
+            self.cells[i].0 |= DOT_BITS[dot_pos.x as usize][dot_pos.y as usize];
+            self.cells[i].1 = c.modifier;
+        }
+    }
+}
+
+impl CanDraw for BrailleCanvas {
+    /// Draw the plotted dots to the canvas as braille characters. Cells with no plotted dots are left untouched
+    fn draw_to(&self, canvas: &mut impl Canvas) {
+        let width = self.cell_size().x;
+
+        for (i, (dots, modifier)) in (0..).zip(&self.cells) {
+            if *dots == 0 {
+                continue;
+            }
+
+            let text_char = char::from_u32(BRAILLE_BASE + u32::from(*dots))
+                .unwrap_or_else(|| unreachable!("All 256 braille patterns are valid characters"));
+            canvas.plot(
+                Vec2D::new(i % width, i / width),
+                ColChar::new(text_char, *modifier),
+            );
+        }
+    }
+}
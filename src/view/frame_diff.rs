@@ -0,0 +1,155 @@
+use super::View;
+use crate::core::{ColChar, ColourDepth, Vec2D, char_width};
+use std::{
+    borrow::Cow,
+    fmt::{self, Display, Formatter},
+};
+
+/// Unchanged cells between two changed runs in the same row are rewritten instead of skipped over if there are this many or fewer of them, as moving the cursor past them would take up more bytes than just writing them again
+const MAX_REWRITTEN_GAP: usize = 4;
+
+/// Return the pixels as they take up columns in the terminal. Each [`ColChar::CONTINUATION`] that follows a wide character is removed, as the wide character covers its column. Wide characters without a continuation after them, continuations without a wide character before them and zero width characters are replaced with spaces, so that every pixel still takes up exactly one column
+pub fn terminal_columns(pixels: &[ColChar]) -> Cow<'_, [ColChar]> {
+    let is_wide = |c: ColChar| c.display_width() == 2;
+    if pixels
+        .iter()
+        .all(|c| !c.is_continuation() && char_width(c.text_char) == 1)
+    {
+        return Cow::Borrowed(pixels);
+    }
+
+    let space = |c: ColChar| ColChar::EMPTY.with_mod(c.modifier);
+    let mut columns = Vec::with_capacity(pixels.len());
+    for (x, &c) in pixels.iter().enumerate() {
+        if c.is_continuation() {
+            if x == 0 || !is_wide(pixels[x - 1]) {
+                columns.push(space(c));
+            }
+        } else if is_wide(c) {
+            if pixels.get(x + 1).is_some_and(|next| next.is_continuation()) {
+                columns.push(c);
+            } else {
+                columns.push(space(c));
+            }
+        } else if char_width(c.text_char) == 0 && !c.is_transparent() {
+            columns.push(space(c));
+        } else {
+            columns.push(c);
+        }
+    }
+    Cow::Owned(columns)
+}
+
+/// Write a run of adjacent [`ColChar`]s, only writing their [`Modifier`](crate::core::Modifier)s where they change. Wide characters are written as described in [`ColChar::CONTINUATION`]
+pub fn write_pixel_run(
+    f: &mut Formatter<'_>,
+    pixels: &[ColChar],
+    depth: ColourDepth,
+) -> fmt::Result {
+    let pixels = terminal_columns(pixels);
+    for x in 0..pixels.len() {
+        pixels[x].display_with_prev_and_next(
+            f,
+            pixels.get(x.wrapping_sub(1)).map(|c| c.modifier),
+            pixels.get(x + 1).map(|c| c.modifier),
+            depth,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// The size and pixels of a [`View`] as they were last written to the terminal
+#[derive(Debug, Clone)]
+pub struct PreviousFrame {
+    size: Vec2D,
+    pixels: Vec<ColChar>,
+}
+
+impl PreviousFrame {
+    /// Store the size and pixels of the rendered `View`, reusing the previously allocated buffer if possible
+    pub fn update(previous: &mut Option<Self>, size: Vec2D, pixels: &[ColChar]) {
+        match previous {
+            Some(frame) => {
+                frame.size = size;
+                frame.pixels.clear();
+                frame.pixels.extend_from_slice(pixels);
+            }
+            None => {
+                *previous = Some(Self {
+                    size,
+                    pixels: pixels.to_vec(),
+                });
+            }
+        }
+    }
+
+    /// Returns a [`FrameDiff`] between this frame and the `View`, or `None` if the `View` has been resized since, in which case it should be fully repainted
+    pub fn diff<'a>(&'a self, view: &'a View) -> Option<FrameDiff<'a>> {
+        (self.size == view.size()).then_some(FrameDiff {
+            previous: self,
+            view,
+        })
+    }
+}
+
+/// When displayed, only writes the runs of pixels in the `View` that have changed since the [`PreviousFrame`], each preceded by an escape code to move the cursor to the start of the run. Assumes that the previous frame was drawn from the top left corner of the terminal, as [`View`]'s `Display` implementation does
+pub struct FrameDiff<'a> {
+    previous: &'a PreviousFrame,
+    view: &'a View,
+}
+
+impl Display for FrameDiff<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let width = self.view.width;
+
+        for y in 0..self.view.height {
+            let row = &self.view.pixels[width * y..width * (y + 1)];
+            let previous_row = &self.previous.pixels[width * y..width * (y + 1)];
+
+            let mut x = 0;
+            while x < width {
+                if row[x] == previous_row[x] {
+                    x += 1;
+                    continue;
+                }
+
+                // Extend the run until it is followed by a long enough gap of unchanged pixels
+                let start = x;
+                let mut end = x + 1;
+                let mut gap = 0;
+                for i in start + 1..width {
+                    if row[i] == previous_row[i] {
+                        gap += 1;
+                        if gap > MAX_REWRITTEN_GAP {
+                            break;
+                        }
+                    } else {
+                        gap = 0;
+                        end = i + 1;
+                    }
+                }
+
+                // Rewrite whole wide characters, as they depend on the cells on both sides of them
+                let start = if start > 0
+                    && (row[start].is_continuation() || row[start - 1].display_width() == 2)
+                {
+                    start - 1
+                } else {
+                    start
+                };
+                if end < width && (row[end].is_continuation() || row[end - 1].display_width() == 2)
+                {
+                    end += 1;
+                }
+
+                write!(f, "\x1b[{};{}H", y + 1, start + 1)?;
+                write_pixel_run(f, &row[start..end], self.view.colour_depth)?;
+                x = end;
+            }
+        }
+
+        // Leave the cursor below the `View`, where a full repaint would have left it
+        write!(f, "\x1b[{};1H\x1b[J", self.view.height + 1)
+    }
+}
@@ -0,0 +1,346 @@
+use super::View;
+use crate::{
+    ascii::Text,
+    core::{CanDraw, Canvas, Modifier, Vec2D},
+};
+use std::{
+    collections::VecDeque,
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+/// The timings and output of a single frame, as recorded by [`FrameStats`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FrameSample {
+    /// The time spent in [`FrameStats::time_draw`] during the frame
+    pub draw: Duration,
+    /// The time spent rendering the `View` with [`FrameStats::display_render`], [`FrameStats::display_render_to`] or in [`FrameStats::time_render`] during the frame
+    pub render: Duration,
+    /// The number of bytes written while rendering the `View`
+    pub bytes_written: usize,
+    /// The time taken by the whole frame, as passed to [`FrameStats::end_frame`]
+    pub total: Duration,
+    /// The time since the previous call to [`FrameStats::end_frame`], including any time spent sleeping, or `None` for the first frame
+    pub interval: Option<Duration>,
+    /// Whether drawing and rendering were skipped this frame because the previous one took too long
+    pub skipped: bool,
+}
+
+/// Collects the timings of each frame, the number of bytes written to the terminal and the number of skipped frames, and keeps rolling averages of them over the last few frames
+///
+/// Wrap your drawing code in [`FrameStats::time_draw`], render with [`FrameStats::display_render`] instead of [`View::display_render`] and call [`FrameStats::end_frame`] at the end of every frame. `end_frame` takes the same arguments as the function passed to [`fps_gameloop!`](crate::fps_gameloop), so it can be passed straight to it. The averages can be drawn on top of the `View` with [`FrameStats::overlay`]
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::{view::{FrameStats, View}, core::{ColChar, ColourDepth, Vec2D}, primitives::Pixel};
+/// # use std::time::Duration;
+/// let mut view = View::new(40, 5, ColChar::EMPTY).with_colour_depth(ColourDepth::TrueColour);
+/// let mut stats = FrameStats::new(30);
+/// let mut output = Vec::new();
+///
+/// for x in 0..10 {
+///     stats.time_draw(|| {
+///         view.clear();
+///         view.draw(&Pixel::new(Vec2D::new(x, 4), ColChar::SOLID));
+///     });
+///     view.draw(&stats.overlay(Vec2D::ZERO));
+///     stats.display_render_to(&mut view, &mut output).unwrap();
+///     stats.end_frame(Duration::from_millis(2), false);
+/// }
+///
+/// assert_eq!(stats.frame_count(), 10);
+/// assert_eq!(stats.samples().map(|s| s.bytes_written).sum::<usize>(), output.len());
+/// assert!(view.to_plain_text().starts_with("FPS"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameStats {
+    window: usize,
+    samples: VecDeque<FrameSample>,
+    current: FrameSample,
+    last_frame_end: Option<Instant>,
+    frame_count: u64,
+    skipped_frame_count: u64,
+}
+
+impl FrameStats {
+    /// Create a new `FrameStats` which averages over the last `window` frames. A `window` of 0 is treated as 1
+    #[must_use]
+    pub fn new(window: usize) -> Self {
+        let window = window.max(1);
+        Self {
+            window,
+            samples: VecDeque::with_capacity(window),
+            current: FrameSample::default(),
+            last_frame_end: None,
+            frame_count: 0,
+            skipped_frame_count: 0,
+        }
+    }
+
+    /// Run `draw` and add the time it took to the current frame's draw time, returning its result
+    pub fn time_draw<T>(&mut self, draw: impl FnOnce() -> T) -> T {
+        let now = Instant::now();
+        let result = draw();
+        self.current.draw += now.elapsed();
+        result
+    }
+
+    /// Run `render` and add the time it took to the current frame's render time, returning its result. Use this if you render in a way that `FrameStats` can't do for you, such as through a [`ScaleFitView`](super::ScaleFitView). The bytes written aren't counted
+    pub fn time_render<T>(&mut self, render: impl FnOnce() -> T) -> T {
+        let now = Instant::now();
+        let result = render();
+        self.current.render += now.elapsed();
+        result
+    }
+
+    /// Display the `View` with [`View::display_render`], recording the time taken and the number of bytes written
+    ///
+    /// # Errors
+    /// Returns the `Result` from writing to `stdout`
+    pub fn display_render(&mut self, view: &mut View) -> io::Result<()> {
+        let now = Instant::now();
+        let mut stdout = io::stdout().lock();
+        view.prepare_stdout(&mut stdout)?;
+        let result = self.write_frame(view, &mut stdout);
+        self.current.render += now.elapsed();
+        result
+    }
+
+    /// Display the `View` with [`View::display_render_to`], recording the time taken and the number of bytes written
+    ///
+    /// # Errors
+    /// Returns the `Result` from writing to `target`
+    pub fn display_render_to(
+        &mut self,
+        view: &mut View,
+        target: &mut impl Write,
+    ) -> io::Result<()> {
+        let now = Instant::now();
+        let result = self.write_frame(view, target);
+        self.current.render += now.elapsed();
+        result
+    }
+
+    fn write_frame(&mut self, view: &mut View, target: &mut impl Write) -> io::Result<()> {
+        let mut counter = ByteCounter {
+            inner: target,
+            count: 0,
+        };
+        let result = view.display_render_to(&mut counter);
+        self.current.bytes_written += counter.count;
+        result
+    }
+
+    /// Finish the current frame, storing its sample and starting a new one. `total` is the time taken by the whole frame and `skipped` is whether drawing and rendering were skipped. These match the arguments passed to the function given to [`fps_gameloop!`](crate::fps_gameloop)
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # use gemini_engine::{view::{FrameStats, View}, core::{ColChar, Vec2D}, fps_gameloop};
+    /// # use std::time::Duration;
+    /// let mut view = View::new(40, 5, ColChar::EMPTY);
+    /// let mut stats = FrameStats::default();
+    ///
+    /// fps_gameloop!(
+    ///     {},
+    ///     {
+    ///         stats.time_draw(|| {
+    ///             view.clear();
+    ///             // Draw the scene...
+    ///         });
+    ///         view.draw(&stats.overlay(Vec2D::ZERO));
+    ///         let _ = stats.display_render(&mut view);
+    ///     },
+    ///     30.0,
+    ///     |total: Duration, frame_skip: bool| stats.end_frame(total, frame_skip)
+    /// );
+    /// ```
+    pub fn end_frame(&mut self, total: Duration, skipped: bool) {
+        let now = Instant::now();
+        let mut sample = std::mem::take(&mut self.current);
+        sample.total = total;
+        sample.skipped = skipped;
+        sample.interval = self.last_frame_end.map(|last| now - last);
+        self.last_frame_end = Some(now);
+
+        self.frame_count += 1;
+        if skipped {
+            self.skipped_frame_count += 1;
+        }
+
+        if self.samples.len() == self.window {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// Return the most recently finished frame's sample
+    #[must_use]
+    pub fn last(&self) -> Option<FrameSample> {
+        self.samples.back().copied()
+    }
+
+    /// Return the samples of the frames in the rolling window, from oldest to newest
+    pub fn samples(&self) -> impl Iterator<Item = &FrameSample> {
+        self.samples.iter()
+    }
+
+    /// Return the number of frames finished with [`FrameStats::end_frame`] since the `FrameStats` was created
+    #[must_use]
+    pub const fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Return the number of skipped frames since the `FrameStats` was created
+    #[must_use]
+    pub const fn skipped_frame_count(&self) -> u64 {
+        self.skipped_frame_count
+    }
+
+    /// Return the average time spent drawing over the rendered (not skipped) frames in the window
+    #[must_use]
+    pub fn average_draw(&self) -> Duration {
+        self.average_rendered(|s| s.draw)
+    }
+
+    /// Return the average time spent rendering over the rendered (not skipped) frames in the window
+    #[must_use]
+    pub fn average_render(&self) -> Duration {
+        self.average_rendered(|s| s.render)
+    }
+
+    /// Return the average time taken by a whole frame over the window
+    #[must_use]
+    pub fn average_total(&self) -> Duration {
+        average(self.samples.iter().map(|s| s.total))
+    }
+
+    /// Return the average number of bytes written over the rendered (not skipped) frames in the window
+    #[must_use]
+    pub fn average_bytes_written(&self) -> f64 {
+        let rendered: Vec<usize> = self
+            .samples
+            .iter()
+            .filter(|s| !s.skipped)
+            .map(|s| s.bytes_written)
+            .collect();
+        if rendered.is_empty() {
+            0.0
+        } else {
+            rendered.iter().sum::<usize>() as f64 / rendered.len() as f64
+        }
+    }
+
+    /// Return the proportion of frames in the window that were skipped, between `0.0` and `1.0`
+    #[must_use]
+    pub fn skip_rate(&self) -> f64 {
+        if self.samples.is_empty() {
+            0.0
+        } else {
+            self.samples.iter().filter(|s| s.skipped).count() as f64 / self.samples.len() as f64
+        }
+    }
+
+    /// Return the number of frames per second actually being reached, measured from the time between calls to [`FrameStats::end_frame`] over the window, or `None` if fewer than two frames have been finished
+    #[must_use]
+    pub fn fps(&self) -> Option<f64> {
+        let mut intervals = self.samples.iter().filter_map(|s| s.interval).peekable();
+        intervals.peek()?;
+        let interval = average(intervals).as_secs_f64();
+        (interval > 0.0).then(|| 1.0 / interval)
+    }
+
+    /// Return a [`FrameStatsOverlay`] which draws the current averages at `pos`
+    #[must_use]
+    pub const fn overlay(&self, pos: Vec2D) -> FrameStatsOverlay<'_> {
+        FrameStatsOverlay {
+            stats: self,
+            pos,
+            modifier: Modifier::None,
+        }
+    }
+
+    fn average_rendered(&self, field: impl Fn(&FrameSample) -> Duration) -> Duration {
+        average(self.samples.iter().filter(|s| !s.skipped).map(field))
+    }
+}
+
+impl Default for FrameStats {
+    /// Create a `FrameStats` which averages over the last 60 frames
+    fn default() -> Self {
+        Self::new(60)
+    }
+}
+
+fn average(durations: impl Iterator<Item = Duration>) -> Duration {
+    let (sum, count) = durations.fold((Duration::ZERO, 0), |(sum, count), d| (sum + d, count + 1));
+    if count == 0 {
+        Duration::ZERO
+    } else {
+        sum / count
+    }
+}
+
+/// Passes everything through to `inner`, counting the bytes written
+struct ByteCounter<'a, W: Write> {
+    inner: &'a mut W,
+    count: usize,
+}
+
+impl<W: Write> Write for ByteCounter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.count += written;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Draws the rolling averages of a [`FrameStats`] as three lines of text, created with [`FrameStats::overlay`]
+///
+/// The lines show the frames per second reached and the percentage of skipped frames, the average draw and render times, and the average number of bytes written per frame
+#[derive(Debug, Clone, Copy)]
+pub struct FrameStatsOverlay<'a> {
+    stats: &'a FrameStats,
+    /// The position of the top left corner of the overlay
+    pub pos: Vec2D,
+    /// The [`Modifier`] the text is drawn with
+    pub modifier: Modifier,
+}
+
+impl FrameStatsOverlay<'_> {
+    /// Return the `FrameStatsOverlay` with an updated `modifier` property. Consumes the original `FrameStatsOverlay`
+    #[must_use]
+    pub fn with_modifier(mut self, modifier: impl Into<Modifier>) -> Self {
+        self.modifier = modifier.into();
+        self
+    }
+
+    /// Return the lines of text drawn by the overlay
+    #[must_use]
+    pub fn lines(&self) -> [String; 3] {
+        let stats = self.stats;
+        let fps = stats
+            .fps()
+            .map_or_else(|| String::from("--"), |fps| format!("{fps:.1}"));
+        [
+            format!("FPS {fps} | skipped {:.0}%", stats.skip_rate() * 100.0),
+            format!(
+                "draw {:.2?} | render {:.2?}",
+                stats.average_draw(),
+                stats.average_render()
+            ),
+            format!("output {:.0} B/frame", stats.average_bytes_written()),
+        ]
+    }
+}
+
+impl CanDraw for FrameStatsOverlay<'_> {
+    fn draw_to(&self, canvas: &mut impl Canvas) {
+        for (y, line) in (0..).zip(self.lines()) {
+            Text::new(self.pos + Vec2D::new(0, y), &line, self.modifier).draw_to(canvas);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use super::WrappingMode;
+use crate::core::{CanDraw, Canvas, ColChar, Modifier, Style, Vec2D};
+
+/// The character used when only the top pixel of a cell is plotted
+const UPPER_HALF_BLOCK: char = '▀';
+/// The character used when only the bottom pixel of a cell is plotted
+const LOWER_HALF_BLOCK: char = '▄';
+
+/// A [`Canvas`] with double the vertical resolution, made by splitting every text character into a top and bottom pixel
+///
+/// Pixels are drawn with `▀` and `▄`. As text characters are about twice as tall as they are wide, this also makes the pixels roughly square.
+///
+/// Anything implementing [`CanDraw`] can be drawn to it, after which the `HalfBlockCanvas` itself can be drawn to a [`View`](super::View) (or any other `Canvas`) at the normal resolution. Only the [`Modifier`] of each plotted [`ColChar`] is kept, as the `text_char` is replaced with a block character. If the two pixels in a cell have different modifiers, they are converted to [`Style`]s and the foreground colour of the bottom pixel is used as the background colour of the cell, so both pixels can be displayed in true colour. If neither pixel has a foreground colour, the whole cell is filled using the top pixel's modifier
+///
+/// If you are drawing a [`Viewport`](crate::view3d::Viewport), you should set its `character_width_multiplier` to `1.0`, as the pixels are already square
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::{view::{View, HalfBlockCanvas}, core::{Attributes, Canvas, ColChar, Colour, Modifier, Style, Vec2D}, primitives::Line};
+/// let mut view = View::new(4, 2, ColChar::EMPTY);
+/// let mut canvas = HalfBlockCanvas::new(view.width, view.height * 2);
+///
+/// canvas.draw(&Line::new(Vec2D::new(0, 0), Vec2D::new(3, 3), ColChar::SOLID));
+/// view.draw(&canvas);
+///
+/// assert_eq!(view.to_plain_text(), "▀▄  \n  ▀▄\n");
+///
+/// // A cell with two different colours shows the top one in the foreground and the bottom one in the background
+/// let (red, blue) = (Colour::rgb(255, 0, 0), Colour::rgb(0, 0, 255));
+/// canvas.clear();
+/// canvas.plot(Vec2D::new(0, 0), ColChar::SOLID.with_colour(red));
+/// canvas.plot(Vec2D::new(0, 1), ColChar::SOLID.with_colour(blue));
+/// view.draw(&canvas);
+///
+/// let cell = view.get(Vec2D::ZERO).unwrap();
+/// assert_eq!(cell.text_char, '▀');
+/// assert_eq!(cell.modifier, Modifier::Style(Style::new().with_fg(red).with_bg(blue)));
+///
+/// // If neither pixel has a colour, both are shown in the terminal's default colour
+/// let bold = Modifier::Style(Style::new().with_attributes(Attributes::BOLD));
+/// canvas.clear();
+/// canvas.plot(Vec2D::new(0, 0), ColChar::SOLID.with_mod(Modifier::None));
+/// canvas.plot(Vec2D::new(0, 1), ColChar::SOLID.with_mod(bold));
+/// view.draw(&canvas);
+///
+/// assert_eq!(view.get(Vec2D::ZERO).unwrap(), ColChar::SOLID);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HalfBlockCanvas {
+    /// The width of the `HalfBlockCanvas`, in pixels. If modified, the canvas should be cleared to account for the new size
+    pub width: usize,
+    /// The height of the `HalfBlockCanvas`, in pixels (two per row of text). If modified, the canvas should be cleared to account for the new size
+    pub height: usize,
+    /// Determine how to handle pixels that are plotted outside the `HalfBlockCanvas`
+    pub wrapping_mode: WrappingMode,
+    pixels: Vec<Option<Modifier>>,
+}
+
+impl HalfBlockCanvas {
+    /// Create a new, empty `HalfBlockCanvas`. The size is given in pixels, so a `HalfBlockCanvas` covering a whole `View` should be twice as tall as it
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        let mut canvas = Self {
+            width,
+            height,
+            wrapping_mode: WrappingMode::Ignore,
+            pixels: Vec::with_capacity(width * height),
+        };
+        canvas.clear();
+
+        canvas
+    }
+
+    /// Return the `HalfBlockCanvas` with an updated `wrapping_mode` property. Consumes the original `HalfBlockCanvas`
+    #[must_use]
+    pub const fn with_wrapping_mode(mut self, wrapping_mode: WrappingMode) -> Self {
+        self.wrapping_mode = wrapping_mode;
+        self
+    }
+
+    /// Return the width and height of the `HalfBlockCanvas` in pixels as a [`Vec2D`]
+    #[must_use]
+    pub const fn size(&self) -> Vec2D {
+        Vec2D::new(self.width as i64, self.height as i64)
+    }
+
+    /// Return [`Vec2D`] coordinates of the centre of the `HalfBlockCanvas`
+    #[must_use]
+    pub fn center(&self) -> Vec2D {
+        self.size() / 2
+    }
+
+    /// Clear the `HalfBlockCanvas` of all pixels
+    pub fn clear(&mut self) {
+        self.pixels = vec![None; self.width * self.height];
+    }
+
+    /// Draw a struct implementing [`CanDraw`] to the `HalfBlockCanvas`
+    #[inline]
+    pub fn draw(&mut self, element: &impl CanDraw) {
+        element.draw_to(self);
+    }
+
+    /// Return the [`ColChar`] that represents the cell made up of the given top and bottom pixels, or `None` if neither has been plotted
+    fn cell(top: Option<Modifier>, bottom: Option<Modifier>) -> Option<ColChar> {
+        match (top, bottom) {
+            (Some(top), Some(bottom)) if top == bottom => Some(ColChar::SOLID.with_mod(top)),
+            (Some(top), Some(bottom)) => {
+                let (top_style, bottom_style) = (Style::from(top), Style::from(bottom));
+                // The terminal's default foreground colour can only be used as the foreground
+                Some(match (top_style.foreground, bottom_style.foreground) {
+                    (_, Some(bg)) => ColChar::new(UPPER_HALF_BLOCK, top_style.with_bg(bg).into()),
+                    (Some(bg), None) => ColChar::new(
+                        LOWER_HALF_BLOCK,
+                        Style {
+                            background: Some(bg),
+                            ..bottom_style
+                        }
+                        .into(),
+                    ),
+                    // Both pixels are the default colour, so fill the whole cell with it
+                    (None, None) => ColChar::SOLID.with_mod(top),
+                })
+            }
+            (Some(top), None) => Some(ColChar::new(UPPER_HALF_BLOCK, top)),
+            (None, Some(bottom)) => Some(ColChar::new(LOWER_HALF_BLOCK, bottom)),
+            (None, None) => None,
+        }
+    }
+}
+
+impl Canvas for HalfBlockCanvas {
+    /// Plot a pixel to the `HalfBlockCanvas`. Only the [`Modifier`] of the [`ColChar`] is used
+    ///
+    /// # Panics
+    /// Will panic if the position is out of bounds of the `HalfBlockCanvas` and `wrapping_mode` is `WrappingMode::Panic`
+    fn plot(&mut self, pos: Vec2D, c: ColChar) {
+        if let Some(wrapped_pos) = self.wrapping_mode.handle_bounds(pos, self.size()) {
+            let i = self.width * wrapped_pos.y as usize + wrapped_pos.x as usize;
+            self.pixels[i] = Some(c.modifier);
+        }
+    }
+}
+
+impl CanDraw for HalfBlockCanvas {
+    /// Draw the plotted pixels to the canvas, combining every two rows of pixels into one row of text characters. Cells where neither pixel has been plotted are left untouched
+    fn draw_to(&self, canvas: &mut impl Canvas) {
+        for y in (0..self.height).step_by(2) {
+            for x in 0..self.width {
+                let top = self.pixels[self.width * y + x];
+                let bottom = (y + 1 < self.height)
+                    .then(|| self.pixels[self.width * (y + 1) + x])
+                    .flatten();
+
+                if let Some(cell) = Self::cell(top, bottom) {
+                    canvas.plot(Vec2D::new(x as i64, y as i64 / 2), cell);
+                }
+            }
+        }
+    }
+}
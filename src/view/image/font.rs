@@ -0,0 +1,457 @@
+//! The bitmap font used to rasterise [`View`](crate::view::View)s. Printable ASCII characters are taken from the public domain X11 `misc-fixed` 8x13 font, and block elements, shades, box drawing lines and braille patterns are drawn procedurally. Any other characters are drawn as an empty box
+
+/// The width of a glyph in pixels
+pub const GLYPH_WIDTH: usize = 8;
+/// The height of a glyph in pixels, which gives cells roughly the same proportions as a terminal's
+pub const GLYPH_HEIGHT: usize = 16;
+
+/// A glyph, one byte per row with the most significant bit being the leftmost pixel
+pub type Glyph = [u8; GLYPH_HEIGHT];
+
+/// The height of the ASCII glyphs in pixels
+const ASCII_GLYPH_HEIGHT: usize = 13;
+/// The number of blank rows above each ASCII glyph, to centre them in the cell
+const ASCII_TOP_PADDING: usize = 1;
+/// The row underlines are drawn on, two pixels below the ASCII glyphs' baseline
+pub const UNDERLINE_ROW: usize = ASCII_TOP_PADDING + 12;
+/// The row strikethroughs are drawn on
+pub const STRIKETHROUGH_ROW: usize = ASCII_TOP_PADDING + 6;
+
+/// The printable ASCII characters from `' '` to `'~'`
+const ASCII_GLYPHS: [[u8; ASCII_GLYPH_HEIGHT]; 95] = [
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // ' '
+    [
+        0x00, 0x00, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00, 0x10, 0x00, 0x00,
+    ], // '!'
+    [
+        0x00, 0x00, 0x24, 0x24, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // '"'
+    [
+        0x00, 0x00, 0x00, 0x24, 0x24, 0x7e, 0x24, 0x7e, 0x24, 0x24, 0x00, 0x00, 0x00,
+    ], // '#'
+    [
+        0x00, 0x00, 0x10, 0x3c, 0x50, 0x50, 0x38, 0x14, 0x14, 0x78, 0x10, 0x00, 0x00,
+    ], // '$'
+    [
+        0x00, 0x00, 0x22, 0x52, 0x24, 0x08, 0x08, 0x10, 0x24, 0x2a, 0x44, 0x00, 0x00,
+    ], // '%'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x30, 0x48, 0x48, 0x30, 0x4a, 0x44, 0x3a, 0x00, 0x00,
+    ], // '&'
+    [
+        0x00, 0x00, 0x10, 0x10, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // '\''
+    [
+        0x00, 0x00, 0x04, 0x08, 0x08, 0x10, 0x10, 0x10, 0x08, 0x08, 0x04, 0x00, 0x00,
+    ], // '('
+    [
+        0x00, 0x00, 0x20, 0x10, 0x10, 0x08, 0x08, 0x08, 0x10, 0x10, 0x20, 0x00, 0x00,
+    ], // ')'
+    [
+        0x00, 0x00, 0x24, 0x18, 0x7e, 0x18, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // '*'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x10, 0x7c, 0x10, 0x10, 0x00, 0x00, 0x00, 0x00,
+    ], // '+'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x30, 0x40, 0x00,
+    ], // ','
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // '-'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x38, 0x10, 0x00,
+    ], // '.'
+    [
+        0x00, 0x00, 0x02, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x80, 0x00, 0x00,
+    ], // '/'
+    [
+        0x00, 0x00, 0x18, 0x24, 0x42, 0x42, 0x42, 0x42, 0x42, 0x24, 0x18, 0x00, 0x00,
+    ], // '0'
+    [
+        0x00, 0x00, 0x10, 0x30, 0x50, 0x10, 0x10, 0x10, 0x10, 0x10, 0x7c, 0x00, 0x00,
+    ], // '1'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x42, 0x02, 0x04, 0x18, 0x20, 0x40, 0x7e, 0x00, 0x00,
+    ], // '2'
+    [
+        0x00, 0x00, 0x7e, 0x02, 0x04, 0x08, 0x1c, 0x02, 0x02, 0x42, 0x3c, 0x00, 0x00,
+    ], // '3'
+    [
+        0x00, 0x00, 0x04, 0x0c, 0x14, 0x24, 0x44, 0x44, 0x7e, 0x04, 0x04, 0x00, 0x00,
+    ], // '4'
+    [
+        0x00, 0x00, 0x7e, 0x40, 0x40, 0x5c, 0x62, 0x02, 0x02, 0x42, 0x3c, 0x00, 0x00,
+    ], // '5'
+    [
+        0x00, 0x00, 0x1c, 0x20, 0x40, 0x40, 0x5c, 0x62, 0x42, 0x42, 0x3c, 0x00, 0x00,
+    ], // '6'
+    [
+        0x00, 0x00, 0x7e, 0x02, 0x04, 0x08, 0x08, 0x10, 0x10, 0x20, 0x20, 0x00, 0x00,
+    ], // '7'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x42, 0x42, 0x3c, 0x42, 0x42, 0x42, 0x3c, 0x00, 0x00,
+    ], // '8'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x42, 0x46, 0x3a, 0x02, 0x02, 0x04, 0x38, 0x00, 0x00,
+    ], // '9'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x38, 0x10, 0x00, 0x00, 0x10, 0x38, 0x10, 0x00,
+    ], // ':'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x38, 0x10, 0x00, 0x00, 0x38, 0x30, 0x40, 0x00,
+    ], // ';'
+    [
+        0x00, 0x00, 0x02, 0x04, 0x08, 0x10, 0x20, 0x10, 0x08, 0x04, 0x02, 0x00, 0x00,
+    ], // '<'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x7e, 0x00, 0x00, 0x00, 0x00,
+    ], // '='
+    [
+        0x00, 0x00, 0x40, 0x20, 0x10, 0x08, 0x04, 0x08, 0x10, 0x20, 0x40, 0x00, 0x00,
+    ], // '>'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x42, 0x02, 0x04, 0x08, 0x08, 0x00, 0x08, 0x00, 0x00,
+    ], // '?'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x42, 0x4e, 0x52, 0x56, 0x4a, 0x40, 0x3c, 0x00, 0x00,
+    ], // '@'
+    [
+        0x00, 0x00, 0x18, 0x24, 0x42, 0x42, 0x42, 0x7e, 0x42, 0x42, 0x42, 0x00, 0x00,
+    ], // 'A'
+    [
+        0x00, 0x00, 0x78, 0x44, 0x42, 0x44, 0x78, 0x44, 0x42, 0x44, 0x78, 0x00, 0x00,
+    ], // 'B'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x40, 0x40, 0x40, 0x40, 0x40, 0x42, 0x3c, 0x00, 0x00,
+    ], // 'C'
+    [
+        0x00, 0x00, 0x78, 0x44, 0x42, 0x42, 0x42, 0x42, 0x42, 0x44, 0x78, 0x00, 0x00,
+    ], // 'D'
+    [
+        0x00, 0x00, 0x7e, 0x40, 0x40, 0x40, 0x78, 0x40, 0x40, 0x40, 0x7e, 0x00, 0x00,
+    ], // 'E'
+    [
+        0x00, 0x00, 0x7e, 0x40, 0x40, 0x40, 0x78, 0x40, 0x40, 0x40, 0x40, 0x00, 0x00,
+    ], // 'F'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x40, 0x40, 0x40, 0x4e, 0x42, 0x46, 0x3a, 0x00, 0x00,
+    ], // 'G'
+    [
+        0x00, 0x00, 0x42, 0x42, 0x42, 0x42, 0x7e, 0x42, 0x42, 0x42, 0x42, 0x00, 0x00,
+    ], // 'H'
+    [
+        0x00, 0x00, 0x7c, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x7c, 0x00, 0x00,
+    ], // 'I'
+    [
+        0x00, 0x00, 0x1f, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x44, 0x38, 0x00, 0x00,
+    ], // 'J'
+    [
+        0x00, 0x00, 0x42, 0x44, 0x48, 0x50, 0x60, 0x50, 0x48, 0x44, 0x42, 0x00, 0x00,
+    ], // 'K'
+    [
+        0x00, 0x00, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x7e, 0x00, 0x00,
+    ], // 'L'
+    [
+        0x00, 0x00, 0x82, 0x82, 0xc6, 0xaa, 0x92, 0x92, 0x82, 0x82, 0x82, 0x00, 0x00,
+    ], // 'M'
+    [
+        0x00, 0x00, 0x42, 0x42, 0x62, 0x52, 0x4a, 0x46, 0x42, 0x42, 0x42, 0x00, 0x00,
+    ], // 'N'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x3c, 0x00, 0x00,
+    ], // 'O'
+    [
+        0x00, 0x00, 0x7c, 0x42, 0x42, 0x42, 0x7c, 0x40, 0x40, 0x40, 0x40, 0x00, 0x00,
+    ], // 'P'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x42, 0x42, 0x42, 0x42, 0x52, 0x4a, 0x3c, 0x02, 0x00,
+    ], // 'Q'
+    [
+        0x00, 0x00, 0x7c, 0x42, 0x42, 0x42, 0x7c, 0x50, 0x48, 0x44, 0x42, 0x00, 0x00,
+    ], // 'R'
+    [
+        0x00, 0x00, 0x3c, 0x42, 0x40, 0x40, 0x3c, 0x02, 0x02, 0x42, 0x3c, 0x00, 0x00,
+    ], // 'S'
+    [
+        0x00, 0x00, 0xfe, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00, 0x00,
+    ], // 'T'
+    [
+        0x00, 0x00, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x3c, 0x00, 0x00,
+    ], // 'U'
+    [
+        0x00, 0x00, 0x82, 0x82, 0x44, 0x44, 0x44, 0x28, 0x28, 0x28, 0x10, 0x00, 0x00,
+    ], // 'V'
+    [
+        0x00, 0x00, 0x82, 0x82, 0x82, 0x82, 0x92, 0x92, 0x92, 0xaa, 0x44, 0x00, 0x00,
+    ], // 'W'
+    [
+        0x00, 0x00, 0x82, 0x82, 0x44, 0x28, 0x10, 0x28, 0x44, 0x82, 0x82, 0x00, 0x00,
+    ], // 'X'
+    [
+        0x00, 0x00, 0x82, 0x82, 0x44, 0x28, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00, 0x00,
+    ], // 'Y'
+    [
+        0x00, 0x00, 0x7e, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x40, 0x7e, 0x00, 0x00,
+    ], // 'Z'
+    [
+        0x00, 0x00, 0x3c, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x3c, 0x00, 0x00,
+    ], // '['
+    [
+        0x00, 0x00, 0x80, 0x80, 0x40, 0x20, 0x10, 0x08, 0x04, 0x02, 0x02, 0x00, 0x00,
+    ], // '\\'
+    [
+        0x00, 0x00, 0x78, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x78, 0x00, 0x00,
+    ], // ']'
+    [
+        0x00, 0x00, 0x10, 0x28, 0x44, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // '^'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xfe, 0x00,
+    ], // '_'
+    [
+        0x00, 0x10, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // '`'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x02, 0x3e, 0x42, 0x46, 0x3a, 0x00, 0x00,
+    ], // 'a'
+    [
+        0x00, 0x00, 0x40, 0x40, 0x40, 0x5c, 0x62, 0x42, 0x42, 0x62, 0x5c, 0x00, 0x00,
+    ], // 'b'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x42, 0x40, 0x40, 0x42, 0x3c, 0x00, 0x00,
+    ], // 'c'
+    [
+        0x00, 0x00, 0x02, 0x02, 0x02, 0x3a, 0x46, 0x42, 0x42, 0x46, 0x3a, 0x00, 0x00,
+    ], // 'd'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x42, 0x7e, 0x40, 0x42, 0x3c, 0x00, 0x00,
+    ], // 'e'
+    [
+        0x00, 0x00, 0x1c, 0x22, 0x20, 0x20, 0x7c, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00,
+    ], // 'f'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x3a, 0x44, 0x44, 0x38, 0x40, 0x3c, 0x42, 0x3c,
+    ], // 'g'
+    [
+        0x00, 0x00, 0x40, 0x40, 0x40, 0x5c, 0x62, 0x42, 0x42, 0x42, 0x42, 0x00, 0x00,
+    ], // 'h'
+    [
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x30, 0x10, 0x10, 0x10, 0x10, 0x7c, 0x00, 0x00,
+    ], // 'i'
+    [
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x44, 0x44, 0x38,
+    ], // 'j'
+    [
+        0x00, 0x00, 0x40, 0x40, 0x40, 0x44, 0x48, 0x70, 0x48, 0x44, 0x42, 0x00, 0x00,
+    ], // 'k'
+    [
+        0x00, 0x00, 0x30, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x7c, 0x00, 0x00,
+    ], // 'l'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xec, 0x92, 0x92, 0x92, 0x92, 0x82, 0x00, 0x00,
+    ], // 'm'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x5c, 0x62, 0x42, 0x42, 0x42, 0x42, 0x00, 0x00,
+    ], // 'n'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x42, 0x42, 0x42, 0x42, 0x3c, 0x00, 0x00,
+    ], // 'o'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x5c, 0x62, 0x42, 0x62, 0x5c, 0x40, 0x40, 0x40,
+    ], // 'p'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x3a, 0x46, 0x42, 0x46, 0x3a, 0x02, 0x02, 0x02,
+    ], // 'q'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x5c, 0x22, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00,
+    ], // 'r'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x3c, 0x42, 0x30, 0x0c, 0x42, 0x3c, 0x00, 0x00,
+    ], // 's'
+    [
+        0x00, 0x00, 0x00, 0x20, 0x20, 0x7c, 0x20, 0x20, 0x20, 0x22, 0x1c, 0x00, 0x00,
+    ], // 't'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x44, 0x44, 0x44, 0x44, 0x44, 0x3a, 0x00, 0x00,
+    ], // 'u'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x44, 0x44, 0x44, 0x28, 0x28, 0x10, 0x00, 0x00,
+    ], // 'v'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x82, 0x82, 0x92, 0x92, 0xaa, 0x44, 0x00, 0x00,
+    ], // 'w'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x42, 0x24, 0x18, 0x18, 0x24, 0x42, 0x00, 0x00,
+    ], // 'x'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x42, 0x42, 0x42, 0x46, 0x3a, 0x02, 0x42, 0x3c,
+    ], // 'y'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x7e, 0x04, 0x08, 0x10, 0x20, 0x7e, 0x00, 0x00,
+    ], // 'z'
+    [
+        0x00, 0x00, 0x0e, 0x10, 0x10, 0x08, 0x30, 0x08, 0x10, 0x10, 0x0e, 0x00, 0x00,
+    ], // '{'
+    [
+        0x00, 0x00, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00, 0x00,
+    ], // '|'
+    [
+        0x00, 0x00, 0x70, 0x08, 0x08, 0x10, 0x0c, 0x10, 0x08, 0x08, 0x70, 0x00, 0x00,
+    ], // '}'
+    [
+        0x00, 0x00, 0x24, 0x54, 0x48, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ], // '~'
+];
+
+/// Return the glyph for a character
+pub fn glyph(c: char) -> Glyph {
+    match c {
+        ' '..='~' => {
+            let mut glyph = [0; GLYPH_HEIGHT];
+            let ascii = ASCII_GLYPHS[c as usize - ' ' as usize];
+            glyph[ASCII_TOP_PADDING..][..ASCII_GLYPH_HEIGHT].copy_from_slice(&ascii);
+            glyph
+        }
+        // `ColChar::TRANSPARENT` and whitespace such as `ColChar::VOID`
+        '\0' => [0; GLYPH_HEIGHT],
+        c if c.is_whitespace() => [0; GLYPH_HEIGHT],
+        '\u{2500}'..='\u{257f}' => box_drawing(c).unwrap_or_else(missing),
+        '\u{2580}'..='\u{259f}' => block_element(c),
+        '\u{2800}'..='\u{28ff}' => braille(c),
+        _ => missing(),
+    }
+}
+
+/// Fill the pixels from `(x1, y1)` up to but not including `(x2, y2)`
+fn filled(x1: usize, y1: usize, x2: usize, y2: usize) -> Glyph {
+    let mut glyph = [0; GLYPH_HEIGHT];
+    let row = (0xffu8 >> x1) & !(0xffu16 >> x2) as u8;
+    for line in &mut glyph[y1..y2] {
+        *line = row;
+    }
+    glyph
+}
+
+/// An empty box, for characters the font doesn't have
+fn missing() -> Glyph {
+    let mut glyph = filled(1, 2, 7, 14);
+    for line in &mut glyph[3..13] {
+        *line &= 0b0100_0010;
+    }
+    glyph
+}
+
+fn block_element(c: char) -> Glyph {
+    let (w, h) = (GLYPH_WIDTH, GLYPH_HEIGHT);
+    let (half_w, half_h) = (w / 2, h / 2);
+    let quadrants = |top_left, top_right, bottom_left, bottom_right| {
+        let mut glyph = [0; GLYPH_HEIGHT];
+        for (quadrant, on) in [
+            (filled(0, 0, half_w, half_h), top_left),
+            (filled(half_w, 0, w, half_h), top_right),
+            (filled(0, half_h, half_w, h), bottom_left),
+            (filled(half_w, half_h, w, h), bottom_right),
+        ] {
+            if on {
+                for (line, quadrant_line) in glyph.iter_mut().zip(quadrant) {
+                    *line |= quadrant_line;
+                }
+            }
+        }
+        glyph
+    };
+    let shade = |pattern: [u8; 2]| {
+        let mut glyph = [0; GLYPH_HEIGHT];
+        for (y, line) in glyph.iter_mut().enumerate() {
+            *line = pattern[y % 2];
+        }
+        glyph
+    };
+
+    match c {
+        '▀' => filled(0, 0, w, half_h),
+        // Lower one eighth to full block
+        '▁'..='█' => filled(0, h - (c as usize - 0x2580) * h / 8, w, h),
+        // Left seven eighths to left one eighth
+        '▉'..='▏' => filled(0, 0, (0x2590 - c as usize) * w / 8, h),
+        '▐' => filled(half_w, 0, w, h),
+        '░' => shade([0b1000_1000, 0b0010_0010]),
+        '▒' => shade([0b1010_1010, 0b0101_0101]),
+        '▓' => shade([0b0111_0111, 0b1101_1101]),
+        '▔' => filled(0, 0, w, h / 8),
+        '▕' => filled(w - w / 8, 0, w, h),
+        '▖' => quadrants(false, false, true, false),
+        '▗' => quadrants(false, false, false, true),
+        '▘' => quadrants(true, false, false, false),
+        '▙' => quadrants(true, false, true, true),
+        '▚' => quadrants(true, false, false, true),
+        '▛' => quadrants(true, true, true, false),
+        '▜' => quadrants(true, true, false, true),
+        '▝' => quadrants(false, true, false, false),
+        '▞' => quadrants(false, true, true, false),
+        _ => quadrants(false, true, true, true), // '▟'
+    }
+}
+
+/// Lines joining the centre of the cell to its edges. Heavy and double lines are drawn as light ones, and the more unusual characters (dashed lines, arcs, diagonals...) aren't supported
+fn box_drawing(c: char) -> Option<Glyph> {
+    // (up, down, left, right)
+    let (up, down, left, right) = match c {
+        '─' | '━' | '═' => (false, false, true, true),
+        '│' | '┃' | '║' => (true, true, false, false),
+        '┌'..='┏' | '╒'..='╔' => (false, true, false, true),
+        '┐'..='┓' | '╕'..='╗' => (false, true, true, false),
+        '└'..='┗' | '╘'..='╚' => (true, false, false, true),
+        '┘'..='┛' | '╛'..='╝' => (true, false, true, false),
+        '├'..='┣' | '╞'..='╠' => (true, true, false, true),
+        '┤'..='┫' | '╡'..='╣' => (true, true, true, false),
+        '┬'..='┳' | '╤'..='╦' => (false, true, true, true),
+        '┴'..='┻' | '╧'..='╩' => (true, false, true, true),
+        '┼'..='╋' | '╪'..='╬' => (true, true, true, true),
+        '╴' | '╸' => (false, false, true, false),
+        '╵' | '╹' => (true, false, false, false),
+        '╶' | '╺' => (false, false, false, true),
+        '╷' | '╻' => (false, true, false, false),
+        _ => return None,
+    };
+
+    let (centre_x, centre_y) = (GLYPH_WIDTH / 2 - 1, GLYPH_HEIGHT / 2 - 1);
+    let mut glyph = [0; GLYPH_HEIGHT];
+    let start_x = if left { 0 } else { centre_x };
+    let end_x = if right { GLYPH_WIDTH } else { centre_x + 1 };
+    if left || right {
+        glyph[centre_y] = filled(start_x, 0, end_x, 1)[0];
+    }
+    let start_y = if up { 0 } else { centre_y };
+    let end_y = if down { GLYPH_HEIGHT } else { centre_y + 1 };
+    if up || down {
+        for line in &mut glyph[start_y..end_y] {
+            *line |= 0x80 >> centre_x;
+        }
+    }
+    Some(glyph)
+}
+
+/// Braille patterns, with each of the eight dots drawn as a 2x2 square
+fn braille(c: char) -> Glyph {
+    // The bit of the pattern for each dot, by row and column
+    const DOT_BITS: [[u32; 2]; 4] = [[0, 3], [1, 4], [2, 5], [6, 7]];
+
+    let pattern = c as u32 - 0x2800;
+    let mut glyph = [0; GLYPH_HEIGHT];
+    for (row, bits) in DOT_BITS.iter().enumerate() {
+        for (column, bit) in bits.iter().enumerate() {
+            if pattern & (1 << bit) != 0 {
+                let x = 1 + column * 4;
+                let y = 1 + row * 4;
+                for line in &mut glyph[y..y + 2] {
+                    *line |= 0b1100_0000 >> x;
+                }
+            }
+        }
+    }
+    glyph
+}
@@ -0,0 +1,223 @@
+use super::View;
+use crate::core::{Attributes, ColChar, Colour, Style};
+use std::io::{self, Write};
+
+mod font;
+
+/// Rasterises a [`View`] into a [`ViewImage`], drawing each [`ColChar`] into a fixed size cell with a bundled bitmap font
+///
+/// The font covers printable ASCII, block elements (`█`, `▀`, `░`...), box drawing lines and braille patterns, which between them cover everything drawn by this library. Other characters are drawn as an empty box, and combining characters are left out. Colours and attributes are taken from each `ColChar`'s [`Modifier`](crate::core::Modifier), in the same way as [`Style::from`]. Pixels with no foreground or background colour use the `ImageExporter`'s `foreground` and `background`
+///
+/// No terminal is needed, so this can be used to produce images for documentation or as artefacts of automated tests
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::{view::{ImageExporter, View}, core::{ColChar, Colour, Vec2D}, primitives::Rect};
+/// let mut view = View::new(4, 2, ColChar::EMPTY);
+/// view.draw(&Rect::new(Vec2D::new(1, 0), Vec2D::new(2, 1), ColChar::SOLID.with_rgb(255, 0, 0)));
+///
+/// let image = ImageExporter::new().with_scale(2).render(&view);
+/// assert_eq!((image.width(), image.height()), (4 * 8 * 2, 2 * 16 * 2));
+/// assert_eq!(image.pixel(20, 10), Some(Colour::rgb(255, 0, 0)));
+/// assert_eq!(image.pixel(0, 0), Some(Colour::BLACK));
+///
+/// let mut ppm = Vec::new();
+/// image.write_ppm(&mut ppm).unwrap();
+/// assert!(ppm.starts_with(b"P6\n64 64\n255\n"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageExporter {
+    /// The colour of characters with no foreground colour. Defaults to [`Colour::WHITE`]
+    pub foreground: Colour,
+    /// The colour behind characters with no background colour. Defaults to [`Colour::BLACK`]
+    pub background: Colour,
+    /// The size of each pixel of the font, in image pixels. Defaults to `1`
+    pub scale: usize,
+}
+
+impl ImageExporter {
+    /// The width of a cell in image pixels at a `scale` of 1
+    pub const CELL_WIDTH: usize = font::GLYPH_WIDTH;
+    /// The height of a cell in image pixels at a `scale` of 1
+    pub const CELL_HEIGHT: usize = font::GLYPH_HEIGHT;
+
+    /// Create an `ImageExporter` which draws white text on a black background at a `scale` of 1
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            foreground: Colour::WHITE,
+            background: Colour::BLACK,
+            scale: 1,
+        }
+    }
+
+    /// Return the `ImageExporter` with the default foreground colour changed
+    #[must_use]
+    pub const fn with_foreground(mut self, foreground: Colour) -> Self {
+        self.foreground = foreground;
+        self
+    }
+
+    /// Return the `ImageExporter` with the default background colour changed
+    #[must_use]
+    pub const fn with_background(mut self, background: Colour) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Return the `ImageExporter` with its `scale` changed. A `scale` of 0 is treated as 1
+    #[must_use]
+    pub const fn with_scale(mut self, scale: usize) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Rasterise the `View` into a [`ViewImage`]
+    #[must_use]
+    pub fn render(&self, view: &View) -> ViewImage {
+        let scale = self.scale.max(1);
+        let (cell_width, cell_height) = (Self::CELL_WIDTH * scale, Self::CELL_HEIGHT * scale);
+        let width = view.width * cell_width;
+        let mut pixels = vec![self.background; width * view.height * cell_height];
+
+        for (y, row) in view.rows().take(view.height).enumerate() {
+            for (x, c) in row.iter().enumerate() {
+                let (foreground, background, glyph) = self.rasterise(*c);
+                for (glyph_y, line) in glyph.iter().enumerate() {
+                    for glyph_x in 0..Self::CELL_WIDTH {
+                        let colour = if line & (0x80 >> glyph_x) == 0 {
+                            background
+                        } else {
+                            foreground
+                        };
+                        for sub_y in 0..scale {
+                            let image_y = y * cell_height + glyph_y * scale + sub_y;
+                            let start = image_y * width + x * cell_width + glyph_x * scale;
+                            pixels[start..start + scale].fill(colour);
+                        }
+                    }
+                }
+            }
+        }
+
+        ViewImage {
+            width,
+            height: view.height * cell_height,
+            pixels,
+        }
+    }
+
+    /// Return the foreground colour, background colour and glyph for a character, with its attributes applied
+    fn rasterise(&self, c: ColChar) -> (Colour, Colour, font::Glyph) {
+        let style = Style::from(c.modifier);
+        let mut foreground = style.foreground.unwrap_or(self.foreground);
+        let mut background = style.background.unwrap_or(self.background);
+        let mut glyph = if c.is_continuation() {
+            font::glyph(' ')
+        } else {
+            font::glyph(c.text_char)
+        };
+
+        let attributes = style.attributes;
+        if attributes.contains(Attributes::DIM) {
+            foreground = foreground.blend(background, 0.5);
+        }
+        if attributes.contains(Attributes::REVERSE) {
+            (foreground, background) = (background, foreground);
+        }
+        if attributes.contains(Attributes::BOLD) {
+            for line in &mut glyph {
+                *line |= *line >> 1;
+            }
+        }
+        if attributes.contains(Attributes::ITALIC) {
+            // Slant the glyph by shifting rows further right the higher up they are
+            for (y, line) in glyph.iter_mut().enumerate() {
+                *line >>= (font::GLYPH_HEIGHT - y) / 6;
+            }
+        }
+        if attributes.contains(Attributes::UNDERLINE) {
+            glyph[font::UNDERLINE_ROW] = 0xff;
+        }
+        if attributes.contains(Attributes::STRIKETHROUGH) {
+            glyph[font::STRIKETHROUGH_ROW] = 0xff;
+        }
+
+        (foreground, background, glyph)
+    }
+}
+
+impl Default for ImageExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An RGB image of a [`View`], created by an [`ImageExporter`] or with [`View::to_image`], which can be written as a PPM or PNG file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewImage {
+    width: usize,
+    height: usize,
+    pixels: Vec<Colour>,
+}
+
+impl ViewImage {
+    /// Return the width of the image in pixels
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Return the height of the image in pixels
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Return the colour of the pixel at the given position, or `None` if it is out of bounds
+    #[must_use]
+    pub fn pixel(&self, x: usize, y: usize) -> Option<Colour> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Return the image's pixels from left to right and top to bottom
+    #[must_use]
+    pub fn pixels(&self) -> &[Colour] {
+        &self.pixels
+    }
+
+    /// Return the image's pixels as 8-bit RGB triplets, from left to right and top to bottom
+    #[must_use]
+    pub fn to_rgb_bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flat_map(|c| [c.r, c.g, c.b]).collect()
+    }
+
+    /// Write the image as a binary PPM (`P6`) file, which most image viewers and converters can open
+    ///
+    /// # Errors
+    /// Returns the `Result` from writing to `target`
+    pub fn write_ppm(&self, mut target: impl Write) -> io::Result<()> {
+        write!(target, "P6\n{} {}\n255\n", self.width, self.height)?;
+        target.write_all(&self.to_rgb_bytes())
+    }
+
+    /// Write the image as a PNG file. Requires the `png` feature
+    ///
+    /// # Errors
+    /// Returns an error if writing to `target` fails, or if the image is too large or empty
+    #[cfg(feature = "png")]
+    pub fn write_png(&self, target: impl Write) -> io::Result<()> {
+        let dimension =
+            |n: usize| u32::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e));
+        let mut encoder =
+            png::Encoder::new(target, dimension(self.width)?, dimension(self.height)?);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.to_rgb_bytes())
+            .map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)
+    }
+}
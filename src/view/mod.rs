@@ -1,15 +1,38 @@
 //! This module is home to the [`View`] struct, a [`Canvas`] that is able to draw to `stdout`.
-use crate::core::{CanDraw, Canvas, ColChar, Vec2D};
+use crate::core::{CanDraw, Canvas, ColChar, ColourDepth, Vec2D};
 use std::{
     fmt::{self, Display, Formatter},
-    io::{self, Write},
+    io::{self, IsTerminal, Write},
 };
 
+mod asciicast;
+mod braille;
+mod frame_diff;
+mod frame_stats;
+mod half_block;
+mod image;
+mod resize_listener;
 mod scale_to_fit;
+mod snapshot;
+mod sub_view;
 mod term_utils;
+mod terminal_session;
+mod web_export;
 mod wrapping;
 
+use frame_diff::PreviousFrame;
+
+pub use asciicast::{CastPlayer, CastRecorder};
+pub use braille::BrailleCanvas;
+pub use frame_stats::{FrameSample, FrameStats, FrameStatsOverlay};
+pub use half_block::HalfBlockCanvas;
+pub use image::{ImageExporter, ViewImage};
+pub use resize_listener::ResizeListener;
 pub use scale_to_fit::ScaleFitView;
+pub use snapshot::{CellDifference, ViewSnapshot};
+pub use sub_view::SubView;
+pub use terminal_session::{SessionOptions, TerminalSession};
+pub use web_export::WebExporter;
 pub use wrapping::WrappingMode;
 
 /// The View struct implements [`Canvas`], and draws to stdout upon calling `display_render`.
@@ -36,7 +59,11 @@ pub struct View {
     pub wrapping_mode: WrappingMode,
     /// If true, [`View::display_render`] will block until the console window is resized to fit the `View`
     pub block_until_resized: bool,
+    /// The colours the terminal is able to display. Any [`Colour`](crate::core::Colour) is quantised to the nearest colour at this depth when the `View` is rendered. Detected with [`ColourDepth::detect`] by default, so set it with [`View::with_colour_depth`] if you need the same output in every environment, for example in tests. If you change this between renders, call [`View::force_full_repaint`] so that unchanged pixels are rewritten too
+    pub colour_depth: ColourDepth,
     pixels: Vec<ColChar>,
+    depth_buffer: Option<Vec<f64>>,
+    previous_frame: Option<PreviousFrame>,
 }
 
 impl View {
@@ -49,7 +76,10 @@ impl View {
             background_char,
             wrapping_mode: WrappingMode::Ignore,
             block_until_resized: false,
+            colour_depth: ColourDepth::detect(),
             pixels: Vec::with_capacity(width * height),
+            depth_buffer: None,
+            previous_frame: None,
         };
         view.clear();
 
@@ -88,6 +118,42 @@ impl View {
         self
     }
 
+    /// Return the `View` with an updated `colour_depth` property. Consumes the original `View`
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::{view::View, core::{ColChar, ColourDepth, Vec2D, Canvas}};
+    /// let mut view = View::new(1, 1, ColChar::EMPTY).with_colour_depth(ColourDepth::Ansi16);
+    /// view.plot(Vec2D::ZERO, ColChar::SOLID.with_rgb(240, 10, 10));
+    ///
+    /// let mut output = Vec::new();
+    /// view.display_render_to(&mut output).unwrap();
+    /// assert!(String::from_utf8(output).unwrap().contains("\x1b[91m█"));
+    /// ```
+    #[must_use]
+    pub const fn with_colour_depth(mut self, colour_depth: ColourDepth) -> Self {
+        self.colour_depth = colour_depth;
+        self
+    }
+
+    /// Return the `View` with a depth buffer, which stores how far away the pixel in every cell is. Pixels plotted with [`Canvas::plot_with_depth`] will then only be drawn if they are closer than the existing pixel, which lets a [`Viewport`](crate::view3d::Viewport) with `depth_testing` enabled draw intersecting faces correctly. Pixels plotted with [`Canvas::plot`] are always drawn and do not change the depth buffer. Consumes the original `View`
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::{view::View, core::{ColChar, Vec2D, Canvas}};
+    /// let mut view = View::new(1, 1, ColChar::EMPTY).with_depth_buffer();
+    ///
+    /// view.plot_with_depth(Vec2D::ZERO, ColChar::SOLID, 2.0);
+    /// view.plot_with_depth(Vec2D::ZERO, ColChar::BACKGROUND, 5.0);
+    /// assert_eq!(view.get(Vec2D::ZERO), Some(ColChar::SOLID));
+    /// ```
+    #[must_use]
+    pub fn with_depth_buffer(mut self) -> Self {
+        self.depth_buffer = Some(vec![]);
+        self.clear();
+        self
+    }
+
     /// Return the width and height of the `View` as a [`Vec2D`]
     #[must_use]
     pub const fn size(&self) -> Vec2D {
@@ -100,9 +166,79 @@ impl View {
         self.size() / 2
     }
 
-    /// Clear the `View` of all pixels, overwriting them all with the set `background_char`
+    /// Return the [`ColChar`] at the given position, or `None` if it is out of bounds. This ignores the `View`'s `wrapping_mode`
+    #[must_use]
+    pub fn get(&self, pos: Vec2D) -> Option<ColChar> {
+        (pos.cmpge(Vec2D::ZERO).all() && pos.cmplt(self.size()).all())
+            .then(|| self.pixels[self.width * pos.y as usize + pos.x as usize])
+    }
+
+    /// Return an iterator over the rows of the `View`, from top to bottom. There is always one row for every unit of the `View`'s height, even if its width is zero
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::{view::{View, ViewSnapshot}, core::ColChar};
+    /// let view = View::new(0, 3, ColChar::EMPTY);
+    /// assert_eq!(view.rows().count(), 3);
+    ///
+    /// let snapshot = view.snapshot();
+    /// assert_eq!(view.to_plain_text(), "\n\n\n");
+    /// assert_eq!(snapshot.to_string().parse::<ViewSnapshot>(), Ok(snapshot));
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = &[ColChar]> {
+        (0..self.height).map(|y| {
+            self.pixels
+                .get(self.width * y..self.width * (y + 1))
+                .unwrap_or_default()
+        })
+    }
+
+    /// Return the `text_char`s and combining characters of the `View` with no modifiers, with a newline after each row. [`ColChar::CONTINUATION`]s are left out, so that wide characters line up as they would in a terminal
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::{view::View, core::{ColChar, Vec2D}, primitives::Rect};
+    /// let mut view = View::new(4, 2, ColChar::EMPTY);
+    /// view.draw(&Rect::new(Vec2D::new(1, 0), Vec2D::new(2, 1), ColChar::SOLID));
+    ///
+    /// assert_eq!(view.to_plain_text(), " ██ \n    \n");
+    /// ```
+    #[must_use]
+    pub fn to_plain_text(&self) -> String {
+        self.snapshot().to_plain_text()
+    }
+
+    /// Return a [`ViewSnapshot`] of the `View`'s current contents, including [`Modifier`](crate::core::Modifier)s, which can be compared against another snapshot
+    #[must_use]
+    pub fn snapshot(&self) -> ViewSnapshot {
+        ViewSnapshot::new(self.width, self.height, &self.pixels)
+    }
+
+    /// Return an image of the `View`'s current contents, rasterised by [`ImageExporter::new`]. Use an [`ImageExporter`] directly to change the colours or scale
+    #[must_use]
+    pub fn to_image(&self) -> ViewImage {
+        ImageExporter::new().render(self)
+    }
+
+    /// Return the `View`'s current contents as an HTML `<pre>` element, converted by [`WebExporter::new`]
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        WebExporter::new().to_html(self)
+    }
+
+    /// Return the `View`'s current contents as an SVG image, converted by [`WebExporter::new`]
+    #[must_use]
+    pub fn to_svg(&self) -> String {
+        WebExporter::new().to_svg(self)
+    }
+
+    /// Clear the `View` of all pixels, overwriting them all with the set `background_char`. If the `View` has a depth buffer, it is reset too
     pub fn clear(&mut self) {
         self.pixels = vec![self.background_char; self.width * self.height];
+        if let Some(depth_buffer) = &mut self.depth_buffer {
+            depth_buffer.clear();
+            depth_buffer.resize(self.width * self.height, f64::INFINITY);
+        }
     }
 
     /// Draw a struct implementing [`CanDraw`] to the `View`
@@ -111,6 +247,12 @@ impl View {
         element.draw_to(self);
     }
 
+    /// Return a [`SubView`] of a rectangular region of the `View`, with its top left corner at `origin`. Anything drawn to the `SubView` is positioned relative to `origin` and clipped to `size`
+    #[must_use]
+    pub const fn sub_view(&mut self, origin: Vec2D, size: Vec2D) -> SubView<'_> {
+        SubView::new(self, origin, size)
+    }
+
     /// Draw a struct implementing [`CanDraw`] to the `View` with a doubled width. Drawing a `Pixel` at `Vec2D(5,3)`, for example, will result in pixels at at `Vec2D(10,3)` and `Vec2D(11,3)` being plotted to. Useful when you want to work with more square pixels, as single text characters are much taller than they are wide
     pub fn draw_double_width(&mut self, element: &impl CanDraw) {
         struct DoubleWidthView<'v>(&'v mut View);
@@ -120,24 +262,136 @@ impl View {
                 self.0.plot(pos, c);
                 self.0.plot(pos + Vec2D::new(1, 0), c);
             }
+
+            fn plot_with_depth(&mut self, pos: Vec2D, c: ColChar, depth: f64) {
+                let pos = pos * Vec2D::new(2, 1);
+                self.0.plot_with_depth(pos, c, depth);
+                self.0.plot_with_depth(pos + Vec2D::new(1, 0), c, depth);
+            }
         }
 
         // Wrap the `View` in a custom struct (defined above), replacing the plot function with one that plots at double width, and pass it to the element as usual. This should be much faster and more memory efficient than storing all of the element's draw calls in a `PixelContainer` before double-width plotting each of them.
         element.draw_to(&mut DoubleWidthView(self));
     }
 
+    /// Plot a pixel to the `View`, blending it over the pixel that is already there with the given opacity, between `0.0` (fully transparent) and `1.0` (fully opaque). See [`ColChar::blend_over`] for how the two pixels are combined
+    ///
+    /// # Panics
+    /// Will panic if the position is out of bounds of the `View` and `wrapping_mode` is `WrappingMode::Panic`
+    pub fn blend(&mut self, pos: Vec2D, c: ColChar, opacity: f64) {
+        if let Some(wrapped_pos) = self.wrapping_mode.handle_bounds(pos, self.size()) {
+            let i = self.width * wrapped_pos.y as usize + wrapped_pos.x as usize;
+            self.pixels[i] = c.blend_over(self.pixels[i], opacity);
+        }
+    }
+
+    /// Draw a struct implementing [`CanDraw`] to the `View` with the given opacity, blending every plotted pixel over the existing one with [`View::blend`]. Useful for shadows, translucent panels or fading particles
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::{view::View, core::{ColChar, Colour, Style, Vec2D}, primitives::Rect};
+    /// let mut view = View::new(4, 1, ColChar::SOLID.with_colour(Colour::rgb(0, 0, 200)));
+    /// let panel = Rect::new(Vec2D::ZERO, Vec2D::new(2, 1), ColChar::EMPTY.with_bg(Colour::WHITE));
+    ///
+    /// view.draw_with_opacity(&panel, 0.25);
+    /// assert_eq!(view.to_plain_text(), "████\n");
+    ///
+    /// let tinted = Style::new().with_fg(Colour::rgb(0, 0, 200)).with_bg(Colour::greyscale(64));
+    /// assert_eq!(view.get(Vec2D::ZERO), Some(ColChar::SOLID.with_style(tinted)));
+    /// ```
+    pub fn draw_with_opacity(&mut self, element: &impl CanDraw, opacity: f64) {
+        struct BlendedView<'v>(&'v mut View, f64);
+        impl Canvas for BlendedView<'_> {
+            fn plot(&mut self, pos: Vec2D, c: ColChar) {
+                self.0.blend(pos, c, self.1);
+            }
+        }
+
+        element.draw_to(&mut BlendedView(self, opacity));
+    }
+
+    /// Draw a struct implementing [`CanDraw`] to the `View` at double the vertical resolution, using a [`HalfBlockCanvas`] covering the whole `View`. Drawing a `Pixel` at `Vec2D(5,3)`, for example, will plot a `▄` to `Vec2D(5,1)`
+    pub fn draw_half_block(&mut self, element: &impl CanDraw) {
+        let mut canvas = HalfBlockCanvas::new(self.width, self.height * 2);
+        canvas.draw(element);
+        self.draw(&canvas);
+    }
+
+    /// Draw a struct implementing [`CanDraw`] to the `View` as braille dots, using a [`BrailleCanvas`] covering the whole `View`. Every text character holds a 2x4 grid of dots, so drawing a `Pixel` at `Vec2D(5,3)`, for example, will plot a `⢀` to `Vec2D(2,0)`
+    pub fn draw_braille(&mut self, element: &impl CanDraw) {
+        let mut canvas = BrailleCanvas::new(self.width * 2, self.height * 4);
+        canvas.draw(element);
+        self.draw(&canvas);
+    }
+
+    /// Make the next call to [`View::display_render`] repaint the whole `View` instead of only the pixels that have changed since the last render. You should call this if anything else has been written over the rendered `View`, e.g. if you printed enough lines below it to scroll the terminal
+    pub fn force_full_repaint(&mut self) {
+        self.previous_frame = None;
+    }
+
     /// Display the `View`. `View` implements the `Display` trait and so can be rendered in many ways (such as `println!("{view}");`), but this is intended to be the fastest way possible.
     ///
+    /// The `View` remembers the last frame it rendered, so after the first call only the pixels that have changed since the previous call are written, along with the escape codes needed to move the cursor to them. The whole `View` is repainted if it has been resized or if [`View::force_full_repaint`] has been called
+    ///
+    /// If `stdout` is a terminal, it will be prepared the first time this is called by printing enough blank lines to make room for the `View`, unless a [`TerminalSession`] is active. To render somewhere other than `stdout`, use [`View::display_render_to`]
+    ///
     /// # Errors
     /// Returns the `Result` from writing to `io::stdout().lock()`. You can simply ignore it with `let _ =` or `.unwrap()` most of the time
-    pub fn display_render(&self) -> io::Result<()> {
+    pub fn display_render(&mut self) -> io::Result<()> {
         let mut stdout = io::stdout().lock();
-        if self.block_until_resized {
-            let view_size = self.size();
-            term_utils::block_until_resized(view_size);
+        self.prepare_stdout(&mut stdout)?;
+
+        self.display_render_to(&mut stdout)
+    }
+
+    /// If `stdout` is a terminal, prepare it and wait for it to be resized to fit the `View` if `block_until_resized` is set
+    fn prepare_stdout(&mut self, stdout: &mut io::StdoutLock) -> io::Result<()> {
+        if stdout.is_terminal() {
+            if !TerminalSession::is_active() {
+                term_utils::prepare_terminal(stdout)?;
+            }
+
+            if self.block_until_resized {
+                let view_size = self.size();
+                if term_utils::block_until_resized(view_size) {
+                    self.force_full_repaint();
+                }
+            }
         }
 
-        write!(stdout, "{self}")
+        Ok(())
+    }
+
+    /// Display the `View` by writing it to `target`, which can be anything implementing [`Write`], such as a file, a socket or a `Vec<u8>`. The frame is written with a single call to [`Write::write_all`]
+    ///
+    /// Unlike [`View::display_render`], this never prepares the terminal or waits for it to be resized. Only the pixels that have changed since the last render are written, so if you switch between targets you should call [`View::force_full_repaint`] first
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::{view::View, core::{ColChar, ColourDepth, Vec2D, Canvas}};
+    /// // Set the colour depth so that the output is the same in every environment
+    /// let mut view = View::new(4, 2, ColChar::EMPTY).with_colour_depth(ColourDepth::TrueColour);
+    /// view.plot(Vec2D::new(1, 0), ColChar::SOLID);
+    ///
+    /// let mut output = Vec::new();
+    /// view.display_render_to(&mut output).unwrap();
+    /// assert!(String::from_utf8(output).unwrap().contains('█'));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns the `Result` from writing to `target`
+    pub fn display_render_to(&mut self, target: &mut impl Write) -> io::Result<()> {
+        let frame = self
+            .previous_frame
+            .as_ref()
+            .and_then(|p| p.diff(self))
+            .map_or_else(|| self.to_string(), |diff| diff.to_string());
+        target.write_all(frame.as_bytes())?;
+
+        let size = self.size();
+        PreviousFrame::update(&mut self.previous_frame, size, &self.pixels);
+
+        Ok(())
     }
 }
 
@@ -153,23 +407,45 @@ impl Canvas for View {
             self.pixels[i] = c;
         }
     }
+
+    /// Plot a pixel to the `View` if it is closer than the pixel already there, as stored in the `View`'s depth buffer. If the `View` doesn't have a depth buffer, this is the same as [`View::plot`](Canvas::plot)
+    ///
+    /// # Panics
+    /// Will panic if the position is out of bounds of the `View` and `wrapping_mode` is `WrappingMode::Panic`
+    fn plot_with_depth(&mut self, pos: Vec2D, c: ColChar, depth: f64) {
+        if let Some(wrapped_pos) = self.wrapping_mode.handle_bounds(pos, self.size()) {
+            let i = self.width * wrapped_pos.y as usize + wrapped_pos.x as usize;
+            if let Some(depth_buffer) = &mut self.depth_buffer {
+                if depth >= depth_buffer[i] {
+                    return;
+                }
+                depth_buffer[i] = depth;
+            }
+            self.pixels[i] = c;
+        }
+    }
+}
+
+impl CanDraw for View {
+    /// Draw the `View`'s pixels to another `Canvas`, with the top left corner at `(0,0)`. Pixels that are [`ColChar::TRANSPARENT`] are skipped
+    fn draw_to(&self, canvas: &mut impl Canvas) {
+        for (y, row) in (0..).zip(self.rows()) {
+            for (x, pixel) in (0..).zip(row) {
+                if !pixel.is_transparent() {
+                    canvas.plot(Vec2D::new(x, y), *pixel);
+                }
+            }
+        }
+    }
 }
 
 impl Display for View {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        term_utils::prepare_terminal(f).map_err(|_| fmt::Error)?;
-
         f.write_str("\x1b[H\x1b[J")?;
         for y in 0..self.height {
             let row = &self.pixels[self.width * y..self.width * (y + 1)];
 
-            for x in 0..row.len() {
-                row[x].display_with_prev_and_next(
-                    f,
-                    row.get(x.wrapping_sub(1)).map(|c| c.modifier),
-                    row.get(x + 1).map(|c| c.modifier),
-                )?;
-            }
+            frame_diff::write_pixel_run(f, row, self.colour_depth)?;
             f.write_str("\r\n")?;
         }
         f.write_str("\x1b[J")?;
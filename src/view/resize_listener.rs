@@ -0,0 +1,143 @@
+use super::term_utils;
+use crate::core::Vec2D;
+use std::{
+    sync::{Condvar, Mutex, OnceLock, PoisonError},
+    time::Duration,
+};
+
+/// How often the terminal size is checked while waiting for a resize on platforms without resize notifications
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The number of resize notifications received so far, which each [`ResizeListener`] compares against the last one it saw
+static RESIZE_COUNT: Mutex<u64> = Mutex::new(0);
+static RESIZED: Condvar = Condvar::new();
+/// Whether resize notifications are being received. Set the first time a [`ResizeListener`] is created
+static NOTIFICATIONS_ENABLED: OnceLock<bool> = OnceLock::new();
+
+/// Start listening for `SIGWINCH` on a background thread, returning `true` if successful
+#[cfg(unix)]
+fn start_watching() -> bool {
+    use signal_hook::{consts::SIGWINCH, iterator::Signals};
+
+    let Ok(mut signals) = Signals::new([SIGWINCH]) else {
+        return false;
+    };
+
+    std::thread::Builder::new()
+        .name(String::from("gemini-resize"))
+        .spawn(move || {
+            for _ in signals.forever() {
+                *RESIZE_COUNT.lock().unwrap_or_else(PoisonError::into_inner) += 1;
+                RESIZED.notify_all();
+            }
+        })
+        .is_ok()
+}
+
+#[cfg(not(unix))]
+const fn start_watching() -> bool {
+    false
+}
+
+fn resize_count() -> u64 {
+    *RESIZE_COUNT.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Reports when the terminal is resized
+///
+/// On Unix, the terminal sends a `SIGWINCH` signal to the program when it is resized, which is received on a background thread, so checking for a resize costs almost nothing and waiting for one sleeps until it happens. On other platforms, the terminal size is checked instead
+///
+/// ## Example
+/// ```no_run
+/// # use gemini_engine::view::ResizeListener;
+/// # use std::time::Duration;
+/// let mut listener = ResizeListener::new();
+///
+/// loop {
+///     if let Some(size) = listener.wait(Duration::from_secs(1)) {
+///         println!("The terminal is now {}x{}", size.x, size.y);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResizeListener {
+    seen_resize_count: u64,
+    last_size: Option<Vec2D>,
+}
+
+impl ResizeListener {
+    /// Create a new `ResizeListener`. Only resizes that happen after it is created are reported
+    #[must_use]
+    pub fn new() -> Self {
+        NOTIFICATIONS_ENABLED.get_or_init(start_watching);
+        Self {
+            seen_resize_count: resize_count(),
+            last_size: term_utils::get_terminal_size_as_vec2d(),
+        }
+    }
+
+    /// Returns `true` if resize notifications are available on this platform. If not, `ResizeListener` checks the size of the terminal instead
+    #[must_use]
+    pub fn has_notifications() -> bool {
+        *NOTIFICATIONS_ENABLED.get_or_init(start_watching)
+    }
+
+    /// Return the new size of the terminal if it has been resized since the last call, or since the `ResizeListener` was created
+    pub fn poll(&mut self) -> Option<Vec2D> {
+        if Self::has_notifications() {
+            let count = resize_count();
+            if count == self.seen_resize_count {
+                return None;
+            }
+            self.seen_resize_count = count;
+        }
+
+        self.check_size()
+    }
+
+    /// Sleep until the terminal is resized or `timeout` passes, returning the new size of the terminal if it was resized. Returns immediately if the terminal has been resized since the last call to [`ResizeListener::poll`] or `wait`
+    pub fn wait(&mut self, timeout: Duration) -> Option<Vec2D> {
+        if Self::has_notifications() {
+            let (count, _) = RESIZED
+                .wait_timeout_while(
+                    RESIZE_COUNT.lock().unwrap_or_else(PoisonError::into_inner),
+                    timeout,
+                    |count| *count == self.seen_resize_count,
+                )
+                .unwrap_or_else(PoisonError::into_inner);
+            self.seen_resize_count = *count;
+            drop(count);
+
+            self.check_size()
+        } else {
+            let start = std::time::Instant::now();
+            loop {
+                if let Some(size) = self.check_size() {
+                    return Some(size);
+                }
+                let remaining = timeout.saturating_sub(start.elapsed());
+                if remaining.is_zero() {
+                    return None;
+                }
+                std::thread::sleep(remaining.min(POLL_INTERVAL));
+            }
+        }
+    }
+
+    /// Return the size of the terminal if it is different to the last size seen
+    fn check_size(&mut self) -> Option<Vec2D> {
+        let size = term_utils::get_terminal_size_as_vec2d();
+        if size == self.last_size {
+            None
+        } else {
+            self.last_size = size;
+            size
+        }
+    }
+}
+
+impl Default for ResizeListener {
+    fn default() -> Self {
+        Self::new()
+    }
+}
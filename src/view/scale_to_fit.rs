@@ -1,25 +1,38 @@
 use crate::{core::Canvas, view::term_utils};
+use std::io::{self, Write};
 
-use super::{ColChar, Vec2D, View};
+use super::{ColChar, ResizeListener, SessionOptions, TerminalSession, Vec2D, View};
 
 /// A wrapper around a [`View`] which auto resizes to fit the terminal window
 ///
-/// `ScaleFitView`'s [`update()`](ScaleFitView::update()) function should be used in place of `View`'s `clear()` function to handle auto-resizing and clearing
+/// `ScaleFitView`'s [`update()`](ScaleFitView::update()) function should be used in place of `View`'s `clear()` function to handle auto-resizing and clearing. The terminal size is only checked again once a [`ResizeListener`] reports that it has been resized
 #[non_exhaustive]
 pub struct ScaleFitView {
     /// The [`View`] that this struct wraps around
     pub view: View,
     /// How many rows to leave clear below the rendered view. You might want to set this if you have more than one line of text after rendered text
     pub empty_row_count: i64,
+    session: Option<TerminalSession>,
+    resize_listener: ResizeListener,
+    terminal_size: Vec2D,
+    on_resize: Option<Box<dyn FnMut(Vec2D)>>,
 }
 
 impl ScaleFitView {
     /// Create a new `ScaleFitView` with the given background `ColChar`
+    ///
+    /// # Panics
+    /// Panics if there is no TTY to get the terminal size of, as per [`terminal_size::terminal_size()`]
     #[must_use]
     pub fn new(background_char: ColChar) -> Self {
         let mut tmp = Self {
             view: View::new(0, 0, background_char),
             empty_row_count: 1,
+            session: None,
+            resize_listener: ResizeListener::new(),
+            terminal_size: term_utils::get_terminal_size_as_vec2d()
+                .expect("Failed to get terminal size"),
+            on_resize: None,
         };
         tmp.update();
         tmp
@@ -32,6 +45,47 @@ impl ScaleFitView {
         self
     }
 
+    /// Return the `ScaleFitView` with a callback which is called by [`ScaleFitView::update`] with the new size of the `View` whenever it is resized. Consumes the original `ScaleFitView`
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # use gemini_engine::{view::ScaleFitView, core::ColChar};
+    /// let mut view = ScaleFitView::new(ColChar::BACKGROUND)
+    ///     .with_resize_callback(|size| eprintln!("Resized to {}x{}", size.x, size.y));
+    /// view.update();
+    /// ```
+    #[must_use]
+    pub fn with_resize_callback(mut self, on_resize: impl FnMut(Vec2D) + 'static) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Return the `ScaleFitView` with a [`TerminalSession`] started with the given options, which restores the terminal when the `ScaleFitView` is dropped. Consumes the original `ScaleFitView`
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # use gemini_engine::{view::{ScaleFitView, SessionOptions}, core::ColChar};
+    /// let mut view = ScaleFitView::new(ColChar::BACKGROUND)
+    ///     .with_terminal_session(SessionOptions::default())
+    ///     .unwrap();
+    /// view.display_render().unwrap();
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the `TerminalSession` couldn't be started, as per [`TerminalSession::new`]
+    pub fn with_terminal_session(mut self, options: SessionOptions) -> io::Result<Self> {
+        self.session = Some(TerminalSession::new(options)?);
+        Ok(self)
+    }
+
+    /// End the `ScaleFitView`'s [`TerminalSession`], if it has one, restoring the terminal
+    ///
+    /// # Errors
+    /// Returns the `Result` from [`TerminalSession::end`]
+    pub fn end_terminal_session(&mut self) -> io::Result<()> {
+        self.session.take().map_or(Ok(()), TerminalSession::end)
+    }
+
     /// Returns the size of the terminal, with the y adjusted as intended using the [`empty_row_count`](ScaleFitView::empty_row_count) property
     ///
     /// # Panics
@@ -45,21 +99,49 @@ impl ScaleFitView {
         term_size.max(Vec2D::ZERO)
     }
 
-    /// Resize and clear the `View`
-    ///
-    /// # Panics
-    /// Panics if there is no TTY to get the terminal size of, as per [`terminal_size::terminal_size()`]
+    /// Resize the `View` if the terminal has been resized, then clear it. When the `View` is resized, it is fully repainted on the next render and the resize callback is called, if there is one
     pub fn update(&mut self) {
-        let term_size = self.intended_size();
-        self.view.width = term_size.x as usize;
-        self.view.height = term_size.y as usize;
+        if let Some(term_size) = self.resize_listener.poll() {
+            self.terminal_size = term_size;
+        }
+
+        let size = (self.terminal_size - Vec2D::new(0, self.empty_row_count + 1)).max(Vec2D::ZERO);
+        if size != self.view.size() {
+            self.view.width = size.x as usize;
+            self.view.height = size.y as usize;
+            self.view.force_full_repaint();
+
+            if let Some(on_resize) = &mut self.on_resize {
+                on_resize(size);
+            }
+        }
 
         self.view.clear();
     }
+
+    /// Display the wrapped `View` to `stdout`. See [`View::display_render`]
+    ///
+    /// # Errors
+    /// Returns the `Result` from writing to `io::stdout().lock()`
+    pub fn display_render(&mut self) -> io::Result<()> {
+        self.view.display_render()
+    }
+
+    /// Display the wrapped `View` by writing it to `target`. See [`View::display_render_to`]
+    ///
+    /// # Errors
+    /// Returns the `Result` from writing to `target`
+    pub fn display_render_to(&mut self, target: &mut impl Write) -> io::Result<()> {
+        self.view.display_render_to(target)
+    }
 }
 
 impl Canvas for ScaleFitView {
     fn plot(&mut self, pos: Vec2D, c: ColChar) {
         self.view.plot(pos, c);
     }
+
+    fn plot_with_depth(&mut self, pos: Vec2D, c: ColChar, depth: f64) {
+        self.view.plot_with_depth(pos, c, depth);
+    }
 }
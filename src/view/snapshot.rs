@@ -0,0 +1,393 @@
+use crate::core::{Attributes, ColChar, Colour, Modifier, Style, Vec2D, char_width};
+use std::{
+    fmt::{self, Display, Formatter, Write},
+    str::FromStr,
+};
+
+/// The key used in a [`ViewSnapshot`]'s modifier grid for pixels with no [`Modifier`]
+const NO_MODIFIER_KEY: char = '.';
+/// The keys used in a [`ViewSnapshot`]'s modifier grid, in order of use. Once these run out, keys are taken from the Latin Extended-A block onwards
+const MODIFIER_KEYS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// A character in a [`ViewSnapshot`]'s text grid, and its combining character
+type Cell = (char, Option<char>);
+
+/// Returns the key used for the `index`th distinct modifier in a [`ViewSnapshot`]'s modifier grid
+fn modifier_key(index: usize) -> char {
+    MODIFIER_KEYS.chars().nth(index).unwrap_or_else(|| {
+        let code = 0x100 + (index - MODIFIER_KEYS.len()) as u32;
+        char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+    })
+}
+
+/// Write a pixel's `text_char` as it appears in a [`ViewSnapshot`]'s text grid. Backslashes, control characters and zero-width characters (which would otherwise be read back as the combining character of the pixel before) are escaped as `\\` and `\u{...}`
+fn push_escaped(text: &mut String, c: char) {
+    if c == '\\' {
+        text.push_str("\\\\");
+    } else if c.is_control() || char_width(c) == 0 {
+        let _ = write!(text, "\\u{{{:x}}}", u32::from(c));
+    } else {
+        text.push(c);
+    }
+}
+
+/// Read an escape written by [`push_escaped`], after its backslash
+fn parse_escape(chars: &mut impl Iterator<Item = char>) -> Result<char, String> {
+    match chars.next() {
+        Some('\\') => Ok('\\'),
+        Some('u') if chars.next() == Some('{') => {
+            let code: String = chars.by_ref().take_while(|c| *c != '}').collect();
+            u32::from_str_radix(&code, 16)
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or_else(|| format!("Invalid character escape `\\u{{{code}}}` in snapshot"))
+        }
+        _ => Err(String::from("Invalid escape in snapshot")),
+    }
+}
+
+fn format_modifier(modifier: Modifier) -> String {
+    match modifier {
+        Modifier::Coded(code) => format!("coded {code}"),
+        Modifier::Colour(c) => format!("colour {},{},{}", c.r, c.g, c.b),
+        Modifier::Style(style) => {
+            let mut parts = vec![String::from("style")];
+            parts.extend(
+                style
+                    .foreground
+                    .map(|c| format!("fg {},{},{}", c.r, c.g, c.b)),
+            );
+            parts.extend(
+                style
+                    .background
+                    .map(|c| format!("bg {},{},{}", c.r, c.g, c.b)),
+            );
+            parts.extend(
+                Attributes::ALL
+                    .iter()
+                    .filter(|(attribute, _, _)| style.attributes.contains(*attribute))
+                    .map(|(_, name, _)| String::from(*name)),
+            );
+            parts.join(" ")
+        }
+        Modifier::None => String::from("none"),
+    }
+}
+
+fn parse_style(s: &str) -> Result<Style, String> {
+    let mut style = Style::new();
+    let mut words = s.split_whitespace();
+
+    while let Some(word) = words.next() {
+        match word {
+            "fg" | "bg" => {
+                let colour = Colour::from_str(words.next().unwrap_or_default())?;
+                if word == "fg" {
+                    style.foreground = Some(colour);
+                } else {
+                    style.background = Some(colour);
+                }
+            }
+            name => {
+                let (attribute, _, _) = Attributes::ALL
+                    .iter()
+                    .find(|(_, n, _)| *n == name)
+                    .ok_or_else(|| format!("Unknown style attribute `{name}`"))?;
+                style.attributes |= *attribute;
+            }
+        }
+    }
+
+    Ok(style)
+}
+
+fn parse_modifier(s: &str) -> Result<Modifier, String> {
+    match s.split_once(' ') {
+        Some(("coded", code)) => code
+            .parse()
+            .map(Modifier::Coded)
+            .map_err(|_| format!("Could not parse modifier code `{code}`")),
+        Some(("colour", colour)) => Colour::from_str(colour).map(Modifier::Colour),
+        Some(("style", style)) => parse_style(style).map(Modifier::Style),
+        _ if s == "style" => Ok(Modifier::Style(Style::new())),
+        _ if s == "none" => Ok(Modifier::None),
+        _ => Err(format!("Unknown modifier `{s}`")),
+    }
+}
+
+/// A single pixel that differs between two [`ViewSnapshot`]s, as returned by [`ViewSnapshot::differences`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellDifference {
+    /// The position of the pixel
+    pub pos: Vec2D,
+    /// The pixel in the snapshot being compared, or `None` if it is out of its bounds
+    pub actual: Option<ColChar>,
+    /// The pixel in the snapshot being compared against, or `None` if it is out of its bounds
+    pub expected: Option<ColChar>,
+}
+
+impl Display for CellDifference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: expected {:?}, found {:?}",
+            self.pos, self.expected, self.actual
+        )
+    }
+}
+
+/// A copy of the contents of a [`View`](super::View), which can be used to check a render in tests. Create one with [`View::snapshot`](super::View::snapshot)
+///
+/// When displayed, a `ViewSnapshot` is written in a plain text format which can be stored as a golden file and parsed back with [`str::parse`]. It is made up of:
+/// - A `<width>x<height>` header
+/// - `height` rows of each pixel's `text_char`, followed by its combining character if it has one. [`ColChar::CONTINUATION`]s are written as the U+FFFF character, and backslashes, control characters and zero-width `text_char`s are escaped as `\\` and `\u{...}`
+/// - `height` rows of keys, one per pixel, where `.` means the pixel has no [`Modifier`]
+/// - A `<key> <modifier>` line for every other key used, such as `a colour 255,0,0`, `b coded 31` or `c style fg 255,255,255 bg 0,0,0 bold`
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::{view::{View, ViewSnapshot}, core::{ColChar, Vec2D, Canvas}};
+/// let mut view = View::new(3, 1, ColChar::EMPTY);
+/// view.plot(Vec2D::new(1, 0), ColChar::SOLID.with_rgb(255, 0, 0));
+///
+/// let snapshot = view.snapshot();
+/// assert_eq!(snapshot.to_string(), "3x1\n █ \n.a.\na colour 255,0,0\n");
+///
+/// let expected: ViewSnapshot = "3x1\n █ \n...\n".parse().unwrap();
+/// let differences = snapshot.differences(&expected);
+/// assert_eq!(differences.len(), 1);
+/// assert_eq!(differences[0].pos, Vec2D::new(1, 0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewSnapshot {
+    width: usize,
+    height: usize,
+    pixels: Vec<ColChar>,
+}
+
+impl ViewSnapshot {
+    /// Create a new `ViewSnapshot` from its size and a row-major slice of pixels
+    ///
+    /// # Panics
+    /// Panics if the number of pixels does not match the size
+    #[must_use]
+    pub fn new(width: usize, height: usize, pixels: &[ColChar]) -> Self {
+        assert_eq!(
+            pixels.len(),
+            width * height,
+            "Snapshot pixels do not match its size"
+        );
+        Self {
+            width,
+            height,
+            pixels: pixels.to_vec(),
+        }
+    }
+
+    /// Return the width and height of the `ViewSnapshot` as a [`Vec2D`]
+    #[must_use]
+    pub const fn size(&self) -> Vec2D {
+        Vec2D::new(self.width as i64, self.height as i64)
+    }
+
+    /// Return the [`ColChar`] at the given position, or `None` if it is out of bounds
+    #[must_use]
+    pub fn get(&self, pos: Vec2D) -> Option<ColChar> {
+        (pos.cmpge(Vec2D::ZERO).all() && pos.cmplt(self.size()).all())
+            .then(|| self.pixels[self.width * pos.y as usize + pos.x as usize])
+    }
+
+    /// Return an iterator over the rows of the `ViewSnapshot`, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[ColChar]> {
+        (0..self.height).map(|y| {
+            self.pixels
+                .get(self.width * y..self.width * (y + 1))
+                .unwrap_or_default()
+        })
+    }
+
+    /// Return the `text_char`s and combining characters of the `ViewSnapshot` with no modifiers, with a newline after each row. [`ColChar::CONTINUATION`]s are left out, so that wide characters line up as they would in a terminal
+    #[must_use]
+    pub fn to_plain_text(&self) -> String {
+        self.text_rows(false)
+    }
+
+    /// Return the text of every row, either as written in the snapshot format (with [`ColChar::CONTINUATION`]s and escapes) or as plain text
+    fn text_rows(&self, snapshot_format: bool) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            for c in row {
+                if snapshot_format {
+                    push_escaped(&mut text, c.text_char);
+                } else if !c.is_continuation() {
+                    text.push(c.text_char);
+                } else {
+                    continue;
+                }
+                text.extend(c.combining);
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Return every pixel that differs between `self` and `expected`. If the snapshots are different sizes, every pixel that is only in one of them is also returned
+    #[must_use]
+    pub fn differences(&self, expected: &Self) -> Vec<CellDifference> {
+        let size = self.size().max(expected.size());
+        let mut differences = vec![];
+
+        for y in 0..size.y {
+            for x in 0..size.x {
+                let pos = Vec2D::new(x, y);
+                let (actual, expected) = (self.get(pos), expected.get(pos));
+                if actual != expected {
+                    differences.push(CellDifference {
+                        pos,
+                        actual,
+                        expected,
+                    });
+                }
+            }
+        }
+
+        differences
+    }
+}
+
+impl Display for ViewSnapshot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}x{}", self.width, self.height)?;
+        f.write_str(&self.text_rows(true))?;
+
+        let mut modifiers: Vec<Modifier> = vec![];
+        for row in self.rows() {
+            for pixel in row {
+                let key = if pixel.modifier == Modifier::None {
+                    NO_MODIFIER_KEY
+                } else if let Some(i) = modifiers.iter().position(|m| *m == pixel.modifier) {
+                    modifier_key(i)
+                } else {
+                    modifiers.push(pixel.modifier);
+                    modifier_key(modifiers.len() - 1)
+                };
+                write!(f, "{key}")?;
+            }
+            writeln!(f)?;
+        }
+
+        for (i, modifier) in modifiers.into_iter().enumerate() {
+            writeln!(f, "{} {}", modifier_key(i), format_modifier(modifier))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ViewSnapshot {
+    type Err = String;
+
+    /// Parse a `ViewSnapshot` from the format it is displayed in
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+
+        let (width, height) = lines
+            .next()
+            .and_then(|header| header.split_once('x'))
+            .and_then(|(w, h)| Some((w.parse::<usize>().ok()?, h.parse::<usize>().ok()?)))
+            .ok_or_else(|| String::from("Snapshot must begin with a `<width>x<height>` header"))?;
+
+        // Each cell is a character followed by its combining character, if it has one
+        let mut read_rows = |is_text: bool| -> Result<Vec<Vec<Cell>>, String> {
+            (0..height)
+                .map(|_| {
+                    let line = lines
+                        .next()
+                        .ok_or_else(|| String::from("Snapshot has too few rows"))?;
+                    let mut row: Vec<Cell> = vec![];
+                    let mut chars = line.chars();
+                    while let Some(c) = chars.next() {
+                        match row.last_mut() {
+                            _ if is_text && c == '\\' => {
+                                row.push((parse_escape(&mut chars)?, None));
+                            }
+                            Some((_, combining @ None)) if is_text && char_width(c) == 0 => {
+                                *combining = Some(c);
+                            }
+                            _ => row.push((c, None)),
+                        }
+                    }
+                    if row.len() == width {
+                        Ok(row)
+                    } else {
+                        Err(format!("Snapshot row should be {width} characters long"))
+                    }
+                })
+                .collect()
+        };
+        let text_rows = read_rows(true)?;
+        let key_rows = read_rows(false)?;
+
+        let mut legend = vec![];
+        for line in lines.filter(|l| !l.is_empty()) {
+            let mut chars = line.chars();
+            let key = chars.next().unwrap_or(NO_MODIFIER_KEY);
+            legend.push((key, parse_modifier(chars.as_str().trim_start())?));
+        }
+
+        let mut pixels = Vec::with_capacity(width * height);
+        for (text_row, key_row) in text_rows.into_iter().zip(key_rows) {
+            for ((text_char, combining), (key, _)) in text_row.into_iter().zip(key_row) {
+                let modifier = if key == NO_MODIFIER_KEY {
+                    Modifier::None
+                } else {
+                    legend
+                        .iter()
+                        .find(|(k, _)| *k == key)
+                        .map(|(_, m)| *m)
+                        .ok_or_else(|| format!("Modifier key `{key}` is not defined"))?
+                };
+                let mut pixel = ColChar::new(text_char, modifier);
+                pixel.combining = combining;
+                pixels.push(pixel);
+            }
+        }
+
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ViewSnapshot;
+    use crate::core::{ColChar, Modifier};
+
+    #[test]
+    fn escaped_text_chars_round_trip() {
+        let pixels = [
+            ColChar::new('\u{301}', Modifier::None),
+            ColChar::new('\r', Modifier::None),
+            ColChar::new('\\', Modifier::None),
+            ColChar::new('e', Modifier::None).with_combining('\u{301}'),
+            ColChar::new('\n', Modifier::None),
+        ];
+        let snapshot = ViewSnapshot::new(5, 1, &pixels);
+
+        let text = snapshot.to_string();
+        assert_eq!(
+            text.lines().nth(1),
+            Some("\\u{301}\\u{d}\\\\e\u{301}\\u{a}")
+        );
+        assert_eq!(text.parse::<ViewSnapshot>(), Ok(snapshot));
+    }
+
+    #[test]
+    fn invalid_escapes_are_rejected() {
+        assert!("1x1\n\\x\n.\n".parse::<ViewSnapshot>().is_err());
+        assert!("1x1\n\\u{110000}\n.\n".parse::<ViewSnapshot>().is_err());
+    }
+}
@@ -0,0 +1,94 @@
+use super::{View, WrappingMode};
+use crate::core::{CanDraw, Canvas, ColChar, Vec2D};
+
+/// A rectangular region of a [`View`] that can be drawn to as its own [`Canvas`], created with [`View::sub_view`]
+///
+/// Positions plotted to a `SubView` are relative to its top left corner, and anything outside of its bounds is handled by its own `wrapping_mode`, so elements such as UI widgets can draw themselves without knowing where they are placed
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::{view::View, core::{ColChar, Modifier, Vec2D}, ascii::Text};
+/// let mut view = View::new(8, 3, ColChar::EMPTY);
+///
+/// let mut panel = view.sub_view(Vec2D::new(2, 1), Vec2D::new(4, 1));
+/// // The text is drawn from the panel's top left corner and cut off at its right edge
+/// panel.draw(&Text::new(Vec2D::ZERO, "Hello!", Modifier::None));
+///
+/// assert_eq!(view.to_plain_text(), "        \n  Hell  \n        \n");
+/// ```
+#[derive(Debug)]
+pub struct SubView<'v> {
+    view: &'v mut View,
+    /// The position of the `SubView`'s top left corner in the `View`
+    pub origin: Vec2D,
+    /// The width and height of the `SubView`
+    pub size: Vec2D,
+    /// Determine how to handle pixels that are plotted outside the `SubView`. Defaults to `WrappingMode::Ignore`, which clips them
+    pub wrapping_mode: WrappingMode,
+}
+
+impl<'v> SubView<'v> {
+    /// Create a new `SubView` of the given `View`
+    #[must_use]
+    pub const fn new(view: &'v mut View, origin: Vec2D, size: Vec2D) -> Self {
+        Self {
+            view,
+            origin,
+            size,
+            wrapping_mode: WrappingMode::Ignore,
+        }
+    }
+
+    /// Return the `SubView` with an updated `wrapping_mode` property. Consumes the original `SubView`
+    #[must_use]
+    pub const fn with_wrapping_mode(mut self, wrapping_mode: WrappingMode) -> Self {
+        self.wrapping_mode = wrapping_mode;
+        self
+    }
+
+    /// Return [`Vec2D`] coordinates of the centre of the `SubView`, relative to its top left corner
+    #[must_use]
+    pub fn center(&self) -> Vec2D {
+        self.size / 2
+    }
+
+    /// Clear the `SubView`'s region of the `View`, overwriting it with the `View`'s `background_char`
+    pub fn clear(&mut self) {
+        let background_char = self.view.background_char;
+        self.fill(background_char);
+    }
+
+    /// Fill the `SubView`'s region of the `View` with the given [`ColChar`]
+    pub fn fill(&mut self, c: ColChar) {
+        for y in 0..self.size.y {
+            for x in 0..self.size.x {
+                self.plot(Vec2D::new(x, y), c);
+            }
+        }
+    }
+
+    /// Draw a struct implementing [`CanDraw`] to the `SubView`
+    #[inline]
+    pub fn draw(&mut self, element: &impl CanDraw) {
+        element.draw_to(self);
+    }
+}
+
+impl Canvas for SubView<'_> {
+    /// Plot a pixel to the `View`, relative to the `SubView`'s top left corner
+    ///
+    /// # Panics
+    /// Will panic if the position is out of bounds of the `SubView` and `wrapping_mode` is `WrappingMode::Panic`, or if it is out of the bounds of the `View` and the `View`'s `wrapping_mode` is `WrappingMode::Panic`
+    fn plot(&mut self, pos: Vec2D, c: ColChar) {
+        if let Some(wrapped_pos) = self.wrapping_mode.handle_bounds(pos, self.size) {
+            self.view.plot(self.origin + wrapped_pos, c);
+        }
+    }
+
+    fn plot_with_depth(&mut self, pos: Vec2D, c: ColChar, depth: f64) {
+        if let Some(wrapped_pos) = self.wrapping_mode.handle_bounds(pos, self.size) {
+            self.view
+                .plot_with_depth(self.origin + wrapped_pos, c, depth);
+        }
+    }
+}
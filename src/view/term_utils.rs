@@ -1,5 +1,10 @@
+use super::ResizeListener;
 use crate::core::Vec2D;
-use std::{fmt, sync::OnceLock};
+use std::{
+    io::{self, Write},
+    sync::OnceLock,
+    time::Duration,
+};
 use terminal_size::terminal_size;
 
 static TERMINAL_PREPARED: OnceLock<bool> = OnceLock::new();
@@ -11,42 +16,48 @@ pub fn get_terminal_size_as_vec2d() -> Option<Vec2D> {
     Some(Vec2D::new(i64::from(width.0), i64::from(height.0)))
 }
 
-/// Blocks the process until the console window is resized to fit `view_size`
-pub fn block_until_resized(view_size: Vec2D) {
-    if let Some(term_size) = get_terminal_size_as_vec2d() {
-        if term_size.cmplt(view_size).any() {
-            println!("Please resize your console window to fit the render\r");
-            loop {
-                let term_size = get_terminal_size_as_vec2d().unwrap_or_else(|| unreachable!());
-                if term_size.cmpge(view_size).all() {
-                    break;
-                }
+/// Blocks the process until the console window is resized to fit `view_size`, sleeping until a [`ResizeListener`] reports a resize. Returns `true` if it had to wait, in which case the terminal will have been written to
+pub fn block_until_resized(view_size: Vec2D) -> bool {
+    if let Some(term_size) = get_terminal_size_as_vec2d()
+        && term_size.cmplt(view_size).any()
+    {
+        println!("Please resize your console window to fit the render\r");
+        let mut listener = ResizeListener::new();
+        loop {
+            // Check the size again in case the terminal was resized before the listener was created
+            let term_size = get_terminal_size_as_vec2d().unwrap_or_else(|| unreachable!());
+            if term_size.cmpge(view_size).all() {
+                break;
             }
+            listener.wait(Duration::from_secs(1));
         }
+        return true;
     }
+
+    false
 }
 
 /// Prepares the console. This is only done the first time this function is called, after which it does nothing. Operations
 /// - Prints blank lines to move previous console lines out of the way
 /// - If on Windows, Calls [`enable_ansi_support()`](https://crates.io/crates/enable-ansi-support) to enable Windows support
 ///
-/// Returns an error if [`terminal_size`] returns `None`, or if it fails to write to the formatter
-pub fn prepare_terminal(f: &mut fmt::Formatter<'_>) -> Result<(), String> {
+/// Returns an error if [`terminal_size`] returns `None`, or if it fails to write to the target
+pub fn prepare_terminal(target: &mut impl Write) -> io::Result<()> {
     // If the console hasn't been prepared before
     if TERMINAL_PREPARED.get().is_none() {
         // Prevent the console from being prepared again
         TERMINAL_PREPARED.get_or_init(|| true);
 
         let Some((_, height)) = terminal_size() else {
-            return Err(String::from("Couldn't get terminal size"));
+            return Err(io::Error::other("Couldn't get terminal size"));
         };
 
-        write!(f, "{}", "\n".repeat(height.0 as usize)).map_err(|e| e.to_string())?;
+        target.write_all("\n".repeat(height.0 as usize).as_bytes())?;
 
         // If using Windows, call a function to enable full ANSI escape code support.
         #[cfg(windows)]
         if enable_ansi_support::enable_ansi_support().is_err() {
-            return Err(String::from("Failed to enable Windows ANSI support."));
+            return Err(io::Error::other("Failed to enable Windows ANSI support."));
         }
     }
 
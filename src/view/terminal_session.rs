@@ -0,0 +1,303 @@
+use std::{
+    io::{self, Write},
+    panic,
+    sync::{Mutex, Once, PoisonError},
+};
+
+/// Escape codes written when a [`TerminalSession`] begins and ends
+const ENTER_ALTERNATE_SCREEN: &str = "\x1b[?1049h";
+const LEAVE_ALTERNATE_SCREEN: &str = "\x1b[?1049l";
+const HIDE_CURSOR: &str = "\x1b[?25l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+const ENABLE_MOUSE_CAPTURE: &str = "\x1b[?1000h\x1b[?1003h\x1b[?1006h";
+const DISABLE_MOUSE_CAPTURE: &str = "\x1b[?1006l\x1b[?1003l\x1b[?1000l";
+
+/// The state needed to restore the terminal once the active [`TerminalSession`] ends. This is kept in a static so that the panic hook can restore it too
+struct SavedState {
+    options: SessionOptions,
+    #[cfg(unix)]
+    original_termios: Option<rustix::termios::Termios>,
+}
+
+static ACTIVE_SESSION: Mutex<Option<SavedState>> = Mutex::new(None);
+static PANIC_HOOK_INSTALLED: Once = Once::new();
+#[cfg(unix)]
+static SIGNAL_HANDLER_INSTALLED: Once = Once::new();
+
+/// Options for how a [`TerminalSession`] should change the terminal. By default, the alternate screen is used and the cursor is hidden, but raw mode and mouse capture are not enabled
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::view::SessionOptions;
+/// let options = SessionOptions::default().with_raw_mode();
+/// assert!(options.alternate_screen && options.hide_cursor && options.raw_mode);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct SessionOptions {
+    /// Switch to the terminal's alternate screen, so that the rendered `View` doesn't pollute the scrollback and the previous contents of the terminal reappear once the session ends
+    pub alternate_screen: bool,
+    /// Hide the cursor while the session is active
+    pub hide_cursor: bool,
+    /// Put the terminal in raw mode, so that input is available one key at a time without being echoed. Note that in raw mode, `Ctrl+C` no longer stops the program and `\n` no longer returns the cursor to the start of the line, so print `\r\n` instead. Raw mode is only supported on Unix
+    pub raw_mode: bool,
+    /// Make the terminal report mouse clicks, movement and scrolling, which can be read as [`MouseEvent`](crate::input::MouseEvent)s with [`Input`](crate::input::Input). This usually stops the mouse from selecting text in the terminal. Raw mode should also be enabled, so that events are received immediately
+    pub mouse_capture: bool,
+}
+
+impl SessionOptions {
+    /// Return the `SessionOptions` with an updated `alternate_screen` property. Consumes the original `SessionOptions`
+    #[must_use]
+    pub const fn with_alternate_screen(mut self, alternate_screen: bool) -> Self {
+        self.alternate_screen = alternate_screen;
+        self
+    }
+
+    /// Return the `SessionOptions` with an updated `hide_cursor` property. Consumes the original `SessionOptions`
+    #[must_use]
+    pub const fn with_hide_cursor(mut self, hide_cursor: bool) -> Self {
+        self.hide_cursor = hide_cursor;
+        self
+    }
+
+    /// Return the `SessionOptions` with raw mode enabled. Consumes the original `SessionOptions`
+    #[must_use]
+    pub const fn with_raw_mode(mut self) -> Self {
+        self.raw_mode = true;
+        self
+    }
+
+    /// Return the `SessionOptions` with mouse capture enabled. Consumes the original `SessionOptions`
+    #[must_use]
+    pub const fn with_mouse_capture(mut self) -> Self {
+        self.mouse_capture = true;
+        self
+    }
+}
+
+impl Default for SessionOptions {
+    fn default() -> Self {
+        Self {
+            alternate_screen: true,
+            hide_cursor: true,
+            raw_mode: false,
+            mouse_capture: false,
+        }
+    }
+}
+
+/// A guard which prepares the terminal for rendering full screen `View`s, and restores it once dropped
+///
+/// Starting a `TerminalSession` switches to the alternate screen, hides the cursor and optionally enables raw mode and mouse capture, as set by its [`SessionOptions`]. Everything is restored when the `TerminalSession` is dropped or [ended](TerminalSession::end), and also if the program panics, so the panic message is printed to the normal screen. On Unix, it is also restored if the program receives `SIGINT` or `SIGTERM`, after which the signal's default action (ending the program) is carried out. While a session is active, [`View::display_render`](super::View::display_render) no longer prints blank lines to make room for the `View`
+///
+/// Only one `TerminalSession` can be active at a time
+///
+/// ## Example
+/// ```no_run
+/// use gemini_engine::{view::{SessionOptions, TerminalSession, View}, core::ColChar};
+///
+/// let _session = TerminalSession::new(SessionOptions::default()).unwrap();
+/// let mut view = View::new(20, 5, ColChar::BACKGROUND);
+///
+/// view.display_render().unwrap();
+/// // The terminal is restored when `_session` is dropped at the end of the scope
+/// ```
+#[derive(Debug)]
+pub struct TerminalSession {
+    options: SessionOptions,
+}
+
+impl TerminalSession {
+    /// Start a new `TerminalSession`, changing the terminal as set by `options`
+    ///
+    /// # Errors
+    /// Returns an error if another `TerminalSession` is already active, if raw mode was requested and could not be enabled (for example because `stdin` is not a terminal, or on platforms other than Unix), or if writing to `stdout` fails
+    pub fn new(options: SessionOptions) -> io::Result<Self> {
+        let mut active_session = ACTIVE_SESSION
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if active_session.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "A TerminalSession is already active",
+            ));
+        }
+
+        #[cfg(windows)]
+        if enable_ansi_support::enable_ansi_support().is_err() {
+            return Err(io::Error::other("Failed to enable Windows ANSI support."));
+        }
+
+        let state = SavedState {
+            options,
+            #[cfg(unix)]
+            original_termios: if options.raw_mode {
+                Some(enable_raw_mode()?)
+            } else {
+                None
+            },
+        };
+        #[cfg(not(unix))]
+        if options.raw_mode {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "Raw mode is only supported on Unix",
+            ));
+        }
+
+        let mut codes = String::new();
+        if options.alternate_screen {
+            codes.push_str(ENTER_ALTERNATE_SCREEN);
+        }
+        if options.hide_cursor {
+            codes.push_str(HIDE_CURSOR);
+        }
+        if options.mouse_capture {
+            codes.push_str(ENABLE_MOUSE_CAPTURE);
+        }
+        let mut stdout = io::stdout().lock();
+        if let Err(err) = stdout
+            .write_all(codes.as_bytes())
+            .and_then(|()| stdout.flush())
+        {
+            let _ = restore(state);
+            return Err(err);
+        }
+
+        *active_session = Some(state);
+        drop(active_session);
+        install_panic_hook();
+        install_signal_handler();
+
+        Ok(Self { options })
+    }
+
+    /// Returns `true` if a `TerminalSession` is currently active
+    #[must_use]
+    pub fn is_active() -> bool {
+        ACTIVE_SESSION
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_some()
+    }
+
+    /// Return the [`SessionOptions`] the `TerminalSession` was started with
+    #[must_use]
+    pub const fn options(&self) -> SessionOptions {
+        self.options
+    }
+
+    /// End the `TerminalSession`, restoring the terminal. This is the same as dropping the `TerminalSession`, but returns any error encountered while restoring it
+    ///
+    /// # Errors
+    /// Returns an error if raw mode could not be disabled or if writing to `stdout` fails
+    pub fn end(self) -> io::Result<()> {
+        end_active_session()
+    }
+}
+
+impl Drop for TerminalSession {
+    fn drop(&mut self) {
+        let _ = end_active_session();
+    }
+}
+
+/// Restore the terminal if a session is active. Does nothing if there is no active session, so this is safe to call more than once
+fn end_active_session() -> io::Result<()> {
+    let state = ACTIVE_SESSION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+    state.map_or(Ok(()), restore)
+}
+
+fn restore(state: SavedState) -> io::Result<()> {
+    #[cfg(unix)]
+    let raw_mode_result = state
+        .original_termios
+        .map_or(Ok(()), |termios| disable_raw_mode(&termios));
+    #[cfg(not(unix))]
+    let raw_mode_result = Ok(());
+
+    let mut codes = String::new();
+    if state.options.mouse_capture {
+        codes.push_str(DISABLE_MOUSE_CAPTURE);
+    }
+    if state.options.hide_cursor {
+        codes.push_str(SHOW_CURSOR);
+    }
+    if state.options.alternate_screen {
+        codes.push_str(LEAVE_ALTERNATE_SCREEN);
+    }
+    let mut stdout = io::stdout().lock();
+    stdout.write_all(codes.as_bytes())?;
+    stdout.flush()?;
+
+    raw_mode_result
+}
+
+/// Install a panic hook which restores the terminal before the panic message is printed. This is only done once, and the hook does nothing if no session is active
+fn install_panic_hook() {
+    PANIC_HOOK_INSTALLED.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            // `try_lock` so that a panic while the lock is held can't deadlock the hook
+            if let Ok(mut active_session) = ACTIVE_SESSION.try_lock()
+                && let Some(state) = active_session.take()
+            {
+                let _ = restore(state);
+            }
+            previous_hook(info);
+        }));
+    });
+}
+
+/// Start listening for `SIGINT` and `SIGTERM` on a background thread, restoring the terminal when one is received and then carrying out the signal's default action. This is only done once, and the terminal is left alone if no session is active
+#[cfg(unix)]
+fn install_signal_handler() {
+    use signal_hook::{
+        consts::{SIGINT, SIGTERM},
+        iterator::Signals,
+        low_level,
+    };
+
+    SIGNAL_HANDLER_INSTALLED.call_once(|| {
+        let Ok(mut signals) = Signals::new([SIGINT, SIGTERM]) else {
+            return;
+        };
+
+        let _ = std::thread::Builder::new()
+            .name(String::from("gemini-terminal-session"))
+            .spawn(move || {
+                for signal in signals.forever() {
+                    let _ = end_active_session();
+                    let _ = low_level::emulate_default_handler(signal);
+                }
+            });
+    });
+}
+
+#[cfg(not(unix))]
+const fn install_signal_handler() {}
+
+/// Put the terminal attached to `stdin` in raw mode, returning its previous settings
+#[cfg(unix)]
+fn enable_raw_mode() -> io::Result<rustix::termios::Termios> {
+    use rustix::termios::{OptionalActions, tcgetattr, tcsetattr};
+
+    let stdin = io::stdin();
+    let original = tcgetattr(&stdin)?;
+    let mut raw = original.clone();
+    raw.make_raw();
+    tcsetattr(&stdin, OptionalActions::Now, &raw)?;
+
+    Ok(original)
+}
+
+#[cfg(unix)]
+fn disable_raw_mode(original: &rustix::termios::Termios) -> io::Result<()> {
+    use rustix::termios::{OptionalActions, tcsetattr};
+
+    tcsetattr(io::stdin(), OptionalActions::Now, original)?;
+    Ok(())
+}
@@ -0,0 +1,248 @@
+use super::{View, frame_diff};
+use crate::core::{Attributes, ColChar, Colour, Style};
+use std::fmt::Write;
+
+/// The width of a cell in an SVG export, relative to the font size. This is the usual width of a monospace font's characters
+const SVG_CELL_WIDTH: f64 = 0.6;
+/// The height of a cell in an SVG export, relative to the font size
+const SVG_LINE_HEIGHT: f64 = 1.2;
+/// The distance from the top of a cell to the text's baseline in an SVG export, relative to the cell's height
+const SVG_BASELINE: f64 = 0.8;
+
+/// Converts a [`View`] into HTML or SVG, to embed exact renders of a scene in web pages and documentation
+///
+/// Runs of pixels with the same [`Style`] are coalesced into a single element, in the same way that [`View::display_render`] only writes a `Modifier`'s escape code when it changes. `Modifier::Coded` colours are converted to their equivalents in [`Colour::ANSI_PALETTE`], as in [`Style::from`], and pixels with no foreground or background colour use the `WebExporter`'s `foreground` and `background`
+///
+/// ## Example
+/// ```
+/// # use gemini_engine::{view::{View, WebExporter}, core::{ColChar, Colour, Vec2D}, primitives::Rect};
+/// let mut view = View::new(4, 2, ColChar::EMPTY);
+/// view.draw(&Rect::new(Vec2D::new(1, 0), Vec2D::new(2, 1), ColChar::SOLID.with_rgb(255, 0, 0)));
+///
+/// let exporter = WebExporter::new();
+/// assert_eq!(
+///     exporter.to_html(&view),
+///     "<pre style=\"color:#ffffff;background-color:#000000\"> <span style=\"color:#ff0000\">██</span> \n    </pre>"
+/// );
+/// assert!(exporter.to_svg(&view).starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WebExporter {
+    /// The colour of characters with no foreground colour. Defaults to [`Colour::WHITE`]
+    pub foreground: Colour,
+    /// The colour behind characters with no background colour. Defaults to [`Colour::BLACK`]
+    pub background: Colour,
+    /// The font size of SVG exports in pixels, which also sets the size of each cell. Defaults to `16.0`. HTML exports use the font size of the page they're in
+    pub font_size: f64,
+}
+
+impl WebExporter {
+    /// Create a `WebExporter` which draws white text on a black background with a font size of 16 pixels
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            foreground: Colour::WHITE,
+            background: Colour::BLACK,
+            font_size: 16.0,
+        }
+    }
+
+    /// Return the `WebExporter` with the default foreground colour changed
+    #[must_use]
+    pub const fn with_foreground(mut self, foreground: Colour) -> Self {
+        self.foreground = foreground;
+        self
+    }
+
+    /// Return the `WebExporter` with the default background colour changed
+    #[must_use]
+    pub const fn with_background(mut self, background: Colour) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Return the `WebExporter` with the SVG font size changed
+    #[must_use]
+    pub const fn with_font_size(mut self, font_size: f64) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Convert the `View` into an HTML `<pre>` element, with a `<span>` for each run of styled pixels
+    #[must_use]
+    pub fn to_html(&self, view: &View) -> String {
+        let mut html = format!(
+            "<pre style=\"color:{};background-color:{}\">",
+            hex(self.foreground),
+            hex(self.background)
+        );
+
+        for (y, row) in view.rows().take(view.height).enumerate() {
+            if y > 0 {
+                html.push('\n');
+            }
+            for (style, _, text) in runs(row) {
+                let css = self.css(style, "color");
+                if css.is_empty() {
+                    html.push_str(&text);
+                } else {
+                    let _ = write!(html, "<span style=\"{css}\">{text}</span>");
+                }
+            }
+        }
+
+        html.push_str("</pre>");
+        html
+    }
+
+    /// Convert the `View` into an SVG image, with a `<text>` element for each run of styled pixels and a `<rect>` behind each run with a background colour. Each run is stretched to exactly fill its cells, so the layout doesn't depend on the font used to display it
+    #[must_use]
+    pub fn to_svg(&self, view: &View) -> String {
+        let cell_width = self.font_size * SVG_CELL_WIDTH;
+        let line_height = round(self.font_size * SVG_LINE_HEIGHT);
+        let width = round(view.width as f64 * cell_width);
+        let height = round(view.height as f64 * line_height);
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\" font-family=\"monospace\" font-size=\"{}\" xml:space=\"preserve\">\n",
+            self.font_size
+        );
+        let _ = writeln!(
+            svg,
+            "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>",
+            hex(self.background)
+        );
+
+        for (y, row) in view.rows().take(view.height).enumerate() {
+            let top = round(y as f64 * line_height);
+            let baseline = round(line_height.mul_add(SVG_BASELINE, top));
+            let mut x = 0;
+
+            for (style, length, text) in runs(row) {
+                let left = round(x as f64 * cell_width);
+                let run_width = round(length as f64 * cell_width);
+                x += length;
+
+                let (_, background) = self.colours(style);
+                if background != self.background {
+                    let _ = writeln!(
+                        svg,
+                        "<rect x=\"{left}\" y=\"{top}\" width=\"{run_width}\" height=\"{line_height}\" fill=\"{}\"/>",
+                        hex(background)
+                    );
+                }
+
+                let is_decorated = style.attributes.contains(Attributes::UNDERLINE)
+                    || style.attributes.contains(Attributes::STRIKETHROUGH);
+                if text.trim().is_empty() && !is_decorated {
+                    continue;
+                }
+                let css = self.css(style, "fill");
+                let style_attribute = if css.is_empty() {
+                    String::new()
+                } else {
+                    format!(" style=\"{css}\"")
+                };
+                let _ = writeln!(
+                    svg,
+                    "<text x=\"{left}\" y=\"{baseline}\" textLength=\"{run_width}\" lengthAdjust=\"spacingAndGlyphs\"{style_attribute}>{text}</text>",
+                );
+            }
+        }
+
+        svg.push_str("</svg>");
+        svg
+    }
+
+    /// Return the foreground and background colours of the style, with `REVERSE` applied
+    fn colours(&self, style: Style) -> (Colour, Colour) {
+        let foreground = style.foreground.unwrap_or(self.foreground);
+        let background = style.background.unwrap_or(self.background);
+        if style.attributes.contains(Attributes::REVERSE) {
+            (background, foreground)
+        } else {
+            (foreground, background)
+        }
+    }
+
+    /// Return the CSS declarations for a style, or an empty string if it has none. `colour_property` is the property used for the text colour, `color` in HTML or `fill` in SVG. Background colours are only included for HTML, as SVG text has no background
+    fn css(&self, style: Style, colour_property: &str) -> String {
+        let mut declarations = vec![];
+        let attributes = style.attributes;
+
+        let (foreground, background) = self.colours(style);
+        let reversed = attributes.contains(Attributes::REVERSE);
+        if style.foreground.is_some() || reversed {
+            declarations.push(format!("{colour_property}:{}", hex(foreground)));
+        }
+        if colour_property == "color" && (style.background.is_some() || reversed) {
+            declarations.push(format!("background-color:{}", hex(background)));
+        }
+
+        if attributes.contains(Attributes::BOLD) {
+            declarations.push(String::from("font-weight:bold"));
+        }
+        if attributes.contains(Attributes::DIM) {
+            declarations.push(String::from("opacity:0.5"));
+        }
+        if attributes.contains(Attributes::ITALIC) {
+            declarations.push(String::from("font-style:italic"));
+        }
+        let decorations: Vec<&str> = [
+            (Attributes::UNDERLINE, "underline"),
+            (Attributes::STRIKETHROUGH, "line-through"),
+        ]
+        .into_iter()
+        .filter(|(attribute, _)| attributes.contains(*attribute))
+        .map(|(_, decoration)| decoration)
+        .collect();
+        if !decorations.is_empty() {
+            declarations.push(format!("text-decoration:{}", decorations.join(" ")));
+        }
+
+        declarations.join(";")
+    }
+}
+
+impl Default for WebExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Split a row into runs of pixels with the same [`Style`], returning each run's style, length in cells and escaped text. Wide characters are handled as they are when a `View` is displayed, as described in [`ColChar::CONTINUATION`]
+fn runs(row: &[ColChar]) -> Vec<(Style, usize, String)> {
+    frame_diff::terminal_columns(row)
+        .chunk_by(|a, b| Style::from(a.modifier) == Style::from(b.modifier))
+        .map(|run| {
+            let length = run.iter().map(|c| c.display_width()).sum();
+            let text = run
+                .iter()
+                .flat_map(|c| [Some(c.text_char), c.combining].into_iter().flatten())
+                .map(escape)
+                .collect();
+            (Style::from(run[0].modifier), length, text)
+        })
+        .collect()
+}
+
+/// Escape a character for HTML and XML. Control characters, including [`ColChar::TRANSPARENT`], become spaces
+fn escape(c: char) -> String {
+    match c {
+        '&' => String::from("&amp;"),
+        '<' => String::from("&lt;"),
+        '>' => String::from("&gt;"),
+        '"' => String::from("&quot;"),
+        c if c.is_control() => String::from(" "),
+        c => c.to_string(),
+    }
+}
+
+/// Round a length to three decimal places, so that floating point errors don't end up in the SVG
+fn round(length: f64) -> f64 {
+    (length * 1000.0).round() / 1000.0
+}
+
+fn hex(colour: Colour) -> String {
+    format!("#{:02x}{:02x}{:02x}", colour.r, colour.g, colour.b)
+}
@@ -1,4 +1,4 @@
-use crate::mesh3d::Vec3D;
+use crate::{core::Colour, mesh3d::Vec3D};
 
 /// Characters for brightness. The first character is the darkest and the last character is the brightest
 pub const BRIGHTNESS_CHARS: &str = ".,-~:;=!*(%#$@";
@@ -15,11 +15,77 @@ pub enum LightType {
         direction: Vec3D,
     },
 
-    /// Light comes from a postion in 3D space. This light type does not currently dissipate over distance, so can be represented with a single position
+    /// Light comes from a postion in 3D space, spreading out in every direction. How it dissipates over distance is set by the [`Light`]'s [`Attenuation`]
     Point {
         /// The position from which the light emanates
         position: Vec3D,
     },
+
+    /// Light comes from a position in 3D space in a cone, like a torch or a stage light. Surfaces within `inner_angle` of the centre of the cone are fully lit, and the light fades out towards `outer_angle`. Like a point light, it dissipates over distance according to the [`Light`]'s [`Attenuation`]
+    Spot {
+        /// The position from which the light emanates
+        position: Vec3D,
+        /// The direction the centre of the cone is pointing
+        direction: Vec3D,
+        /// The angle between the centre and the edge of the fully lit part of the cone, in degrees
+        inner_angle: f64,
+        /// The angle between the centre and the edge of the cone, in degrees. Nothing outside this angle is lit
+        outer_angle: f64,
+    },
+}
+
+/// How the light from a [`LightType::Point`] or [`LightType::Spot`] dissipates over distance
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Attenuation {
+    /// The light doesn't dissipate at all, and lights everything with the same intensity no matter how far away it is
+    #[default]
+    None,
+    /// The light's intensity is divided by `constant + linear * d + quadratic * d²`, where `d` is the distance from the light. Real light falls off with the square of the distance
+    Coefficients {
+        /// The constant term. Should usually be `1.0`, so that the light is never brighter than its intensity
+        constant: f64,
+        /// The term multiplied by the distance
+        linear: f64,
+        /// The term multiplied by the square of the distance
+        quadratic: f64,
+    },
+    /// The light fades out smoothly and reaches nothing at the given distance, by a factor of `(1 - (d / range)²)²`
+    Range(f64),
+}
+
+impl Attenuation {
+    /// Return the amount that the light's intensity is multiplied by at the given distance from the light
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::view3d::Attenuation;
+    /// let quadratic = Attenuation::Coefficients { constant: 1.0, linear: 0.0, quadratic: 1.0 };
+    /// assert_eq!(quadratic.factor(2.0), 0.2);
+    ///
+    /// let range = Attenuation::Range(10.0);
+    /// assert_eq!(range.factor(0.0), 1.0);
+    /// assert_eq!(range.factor(12.0), 0.0);
+    /// ```
+    #[must_use]
+    pub fn factor(self, distance: f64) -> f64 {
+        match self {
+            Self::None => 1.0,
+            Self::Coefficients {
+                constant,
+                linear,
+                quadratic,
+            } => {
+                let divisor =
+                    quadratic.mul_add(distance * distance, linear.mul_add(distance, constant));
+                if divisor > 0.0 { divisor.recip() } else { 1.0 }
+            }
+            Self::Range(range) => {
+                let ratio = distance / range;
+                let falloff = ratio.mul_add(-ratio, 1.0).max(0.0);
+                falloff * falloff
+            }
+        }
+    }
 }
 
 /// A light object used to define a scene's lighting. Used by [`DisplayMode::Illuminated`](super::DisplayMode::Illuminated)
@@ -29,6 +95,10 @@ pub struct Light {
     pub light_type: LightType,
     /// The intensity of the light
     pub intensity: f64,
+    /// How the light dissipates over distance. Only used by [`LightType::Point`] and [`LightType::Spot`]. Defaults to [`Attenuation::None`]
+    pub attenuation: Attenuation,
+    /// The colour of the light, which faces' colours are multiplied by if the [`Viewport`](crate::view3d::Viewport)'s [`LightColouring`](crate::view3d::LightColouring) uses colour. Defaults to [`Colour::WHITE`]
+    pub colour: Colour,
 }
 
 impl Light {
@@ -38,6 +108,8 @@ impl Light {
         Self {
             light_type: LightType::Ambient,
             intensity,
+            attenuation: Attenuation::None,
+            colour: Colour::WHITE,
         }
     }
 
@@ -47,6 +119,8 @@ impl Light {
         Self {
             light_type: LightType::Directional { direction },
             intensity,
+            attenuation: Attenuation::None,
+            colour: Colour::WHITE,
         }
     }
 
@@ -56,33 +130,164 @@ impl Light {
         Self {
             light_type: LightType::Point { position },
             intensity,
+            attenuation: Attenuation::None,
+            colour: Colour::WHITE,
         }
     }
 
-    fn calculate_intensity_for_direction(&self, normal: Vec3D, direction: Vec3D) -> f64 {
-        let n_dot_l = normal.dot(direction);
-        if n_dot_l > 0.0 {
-            self.intensity * n_dot_l / (normal.length() * direction.length())
-        } else {
-            0.0
+    /// Create a new spot light. The angles are in degrees, as described in [`LightType::Spot`]
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::{mesh3d::Vec3D, view3d::{Attenuation, Light}};
+    /// let torch = Light::new_spot(1.0, Vec3D::ZERO, Vec3D::Z, 15.0, 25.0)
+    ///     .with_attenuation(Attenuation::Range(20.0));
+    ///
+    /// // A surface straight ahead of the torch
+    /// let lit = torch.calculate_intensity(Vec3D::new(0.0, 0.0, 5.0), Vec3D::Z);
+    /// assert!(lit > 0.8);
+    /// // The same surface off to the side, outside of the cone
+    /// let unlit = torch.calculate_intensity(Vec3D::new(5.0, 0.0, 5.0), Vec3D::Z);
+    /// assert_eq!(unlit, 0.0);
+    /// ```
+    #[must_use]
+    pub const fn new_spot(
+        intensity: f64,
+        position: Vec3D,
+        direction: Vec3D,
+        inner_angle: f64,
+        outer_angle: f64,
+    ) -> Self {
+        Self {
+            light_type: LightType::Spot {
+                position,
+                direction,
+                inner_angle,
+                outer_angle,
+            },
+            intensity,
+            attenuation: Attenuation::None,
+            colour: Colour::WHITE,
         }
     }
 
-    /// Calculate the intensity of the light as it affects a surface with the given normal
+    /// Return the `Light` with an updated `attenuation` property. Consumes the original `Light`
     #[must_use]
-    pub fn calculate_intensity(&self, point: Vec3D, normal: Vec3D) -> f64 {
+    pub const fn with_attenuation(mut self, attenuation: Attenuation) -> Self {
+        self.attenuation = attenuation;
+        self
+    }
+
+    /// Return the `Light` with an updated `colour` property. Consumes the original `Light`
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::{core::Colour, mesh3d::Vec3D, view3d::Light};
+    /// let sunset = Light::new_directional(0.7, Vec3D::new(1.0, 0.2, 1.0))
+    ///     .with_colour(Colour::rgb(255, 140, 60));
+    /// ```
+    #[must_use]
+    pub const fn with_colour(mut self, colour: Colour) -> Self {
+        self.colour = colour;
+        self
+    }
+
+    /// Return the direction the light is travelling in when it reaches `point`, and the amount that its intensity there is multiplied by due to attenuation and the shape of its cone. Returns `None` for ambient lights, which have no direction
+    fn incoming(&self, point: Vec3D) -> Option<(Vec3D, f64)> {
         match self.light_type {
-            LightType::Ambient => self.intensity,
+            LightType::Ambient => None,
 
-            LightType::Directional { direction } => {
-                self.calculate_intensity_for_direction(normal, direction)
-            }
+            LightType::Directional { direction } => Some((direction, 1.0)),
 
             LightType::Point { position } => {
                 let direction = point - position;
+                Some((direction, self.attenuation.factor(direction.length())))
+            }
 
-                self.calculate_intensity_for_direction(normal, direction)
+            LightType::Spot {
+                position,
+                direction: spot_direction,
+                inner_angle,
+                outer_angle,
+            } => {
+                let direction = point - position;
+
+                // Fade smoothly from the edge of the fully lit inner cone to the edge of the outer cone
+                let cos_angle = direction
+                    .normalize_or_zero()
+                    .dot(spot_direction.normalize_or_zero());
+                let (cos_inner, cos_outer) = (
+                    inner_angle.to_radians().cos(),
+                    outer_angle.to_radians().cos(),
+                );
+                let cone = if cos_inner > cos_outer {
+                    let t = ((cos_angle - cos_outer) / (cos_inner - cos_outer)).clamp(0.0, 1.0);
+                    t * t * 2.0f64.mul_add(-t, 3.0)
+                } else if cos_angle >= cos_outer {
+                    1.0
+                } else {
+                    0.0
+                };
+
+                Some((
+                    direction,
+                    cone * self.attenuation.factor(direction.length()),
+                ))
             }
         }
     }
+
+    /// Calculate the intensity of the light as it affects a surface with the given normal
+    #[must_use]
+    pub fn calculate_intensity(&self, point: Vec3D, normal: Vec3D) -> f64 {
+        let Some((direction, falloff)) = self.incoming(point) else {
+            return self.intensity;
+        };
+
+        let n_dot_l = normal.dot(direction);
+        if n_dot_l > 0.0 {
+            self.intensity * falloff * n_dot_l / (normal.length() * direction.length())
+        } else {
+            0.0
+        }
+    }
+
+    /// Calculate the intensity of the light's specular highlight on a surface with the given normal, as seen from `camera_position`, using the Blinn-Phong model. Higher `shininess` values make smaller, sharper highlights. Ambient lights and surfaces facing away from the light have no highlight
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::{mesh3d::Vec3D, view3d::Light};
+    /// let light = Light::new_point(1.0, Vec3D::new(0.0, 0.0, -5.0));
+    /// let point = Vec3D::new(0.0, 0.0, 5.0);
+    ///
+    /// // Seen from the same side as the light, the surface reflects it straight back
+    /// let highlight = light.calculate_specular(point, Vec3D::Z, Vec3D::new(0.0, 0.0, -5.0), 32.0);
+    /// assert!((highlight - 1.0).abs() < 1e-9);
+    /// // Seen from an angle, the highlight is much weaker
+    /// let glancing = light.calculate_specular(point, Vec3D::Z, Vec3D::new(5.0, 0.0, 0.0), 32.0);
+    /// assert!(glancing < 0.1);
+    /// ```
+    #[must_use]
+    pub fn calculate_specular(
+        &self,
+        point: Vec3D,
+        normal: Vec3D,
+        camera_position: Vec3D,
+        shininess: f64,
+    ) -> f64 {
+        let Some((direction, falloff)) = self.incoming(point) else {
+            return 0.0;
+        };
+        if normal.dot(direction) <= 0.0 {
+            return 0.0;
+        }
+
+        // Normals point into the mesh and `direction` points away from the light, so both are flipped to get the usual vectors pointing away from the surface
+        let to_light = -direction.normalize_or_zero();
+        let to_camera = (camera_position - point).normalize_or_zero();
+        let halfway = (to_light + to_camera).normalize_or_zero();
+        let n_dot_h = (-normal.normalize_or_zero()).dot(halfway).max(0.0);
+
+        self.intensity * falloff * n_dot_h.powf(shininess)
+    }
 }
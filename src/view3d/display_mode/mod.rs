@@ -11,9 +11,33 @@ pub enum DisplayMode {
     },
     /// Renders the full, unshaded faces of all the meshes.
     Solid,
-    /// Renders with faces' `text_char`s replaced with other characters to emulate light, based on a passed list of [`Light`]s
+    /// Renders with faces' `text_char`s replaced with other characters and/or their colours multiplied by the colour of the light to emulate light, based on a passed list of [`Light`]s. How the light varies across each face is set by the [`Viewport`](super::Viewport)'s [`Shading`], and how it changes each pixel by its [`LightColouring`]
     Illuminated {
         /// The collection of lights used to illuminate the scene
         lights: Vec<Light>,
     },
 }
+
+/// `Shading` determines how light varies across each face in [`DisplayMode::Illuminated`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Shading {
+    /// Each face is lit with a single intensity, calculated from the face's normal at its centre. This is the fastest mode, but makes curved meshes look faceted
+    #[default]
+    Flat,
+    /// The intensity is calculated at each vertex from its vertex normal, then interpolated across the face (Gouraud shading). This smooths out curved meshes at little extra cost, but highlights from point lights may be lost in the middle of large faces
+    Gouraud,
+    /// The vertex normals and positions are interpolated across the face and the intensity is calculated separately for every pixel. This is the smoothest and most accurate mode, and the slowest
+    PerPixel,
+}
+
+/// `LightColouring` determines how light changes the appearance of faces in [`DisplayMode::Illuminated`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LightColouring {
+    /// The `text_char` of each pixel is replaced with one from [`BRIGHTNESS_CHARS`](lighting::BRIGHTNESS_CHARS) according to the intensity of the light. Colours are left alone, so coloured lights look the same as white ones
+    #[default]
+    Characters,
+    /// The foreground colour of each pixel is multiplied by the total colour of the lights, and its `text_char` is left alone. Pixels with no foreground colour are treated as white. This needs a terminal with true colour support to look its best
+    Colour,
+    /// Both of the above: the `text_char` is replaced according to the intensity of the light, and the colour is multiplied by the colour of the lights
+    ColourAndCharacters,
+}
@@ -0,0 +1,141 @@
+use super::{BRIGHTNESS_CHARS, Light, LightColouring};
+use crate::{
+    core::{ColChar, Colour, Modifier, Style},
+    mesh3d::{Material, Vec3D},
+};
+use std::{
+    iter::Sum,
+    ops::{Add, Mul},
+};
+
+/// The total light falling on a point, summed from every [`Light`] in the scene
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Illumination {
+    /// The total intensity of the lights, used to pick from [`BRIGHTNESS_CHARS`]
+    pub intensity: f64,
+    /// The total colour of the diffuse and emitted light, with each channel's intensity scaled to between `0.0` and `1.0` for each light. Surfaces' colours are multiplied by this
+    pub colour: Vec3D,
+    /// The total colour of the specular highlights, which is added on top of surfaces' colours so that highlights on coloured surfaces take on the colour of the light
+    pub specular: Vec3D,
+}
+
+impl Illumination {
+    /// Sum the light from all of `lights` falling on a surface at `point` with the given `normal` and `material`, including specular highlights as seen from `camera_position`
+    pub fn from_lights(
+        lights: &[Light],
+        point: Vec3D,
+        normal: Vec3D,
+        camera_position: Vec3D,
+        material: Material,
+    ) -> Self {
+        let emitted = Self {
+            intensity: material.emissive,
+            colour: Vec3D::splat(material.emissive),
+            specular: Vec3D::ZERO,
+        };
+
+        lights
+            .iter()
+            .map(|light| {
+                let colour = colour_to_vec(light.colour);
+                let diffuse = material.diffuse * light.calculate_intensity(point, normal);
+                let specular = if material.specular > 0.0 {
+                    material.specular
+                        * light.calculate_specular(
+                            point,
+                            normal,
+                            camera_position,
+                            material.shininess,
+                        )
+                } else {
+                    0.0
+                };
+
+                Self {
+                    intensity: diffuse + specular,
+                    colour: colour * diffuse,
+                    specular: colour * specular,
+                }
+            })
+            .fold(emitted, Add::add)
+    }
+
+    /// Apply the illumination to a pixel. Depending on `colouring`, its `text_char` is replaced with one from [`BRIGHTNESS_CHARS`] and/or its foreground colour is multiplied by the colour of the light
+    pub fn apply(self, c: ColChar, colouring: LightColouring) -> ColChar {
+        let c = if colouring == LightColouring::Colour {
+            c
+        } else {
+            c.with_char(brightness_char(self.intensity))
+        };
+
+        if colouring == LightColouring::Characters {
+            c
+        } else {
+            c.with_mod(self.tint(c.modifier))
+        }
+    }
+
+    /// Multiply the modifier's foreground colour by the colour of the light and add the specular highlights, keeping its background colour and attributes. Pixels with no foreground colour are treated as white
+    fn tint(self, modifier: Modifier) -> Modifier {
+        let mut style = Style::from(modifier);
+        let base = colour_to_vec(style.foreground.unwrap_or(Colour::WHITE));
+        let lit = (base.mul_add(self.colour, self.specular) * 255.0)
+            .clamp(Vec3D::ZERO, Vec3D::splat(255.0));
+        let lit = Colour::rgb(
+            lit.x.round() as u8,
+            lit.y.round() as u8,
+            lit.z.round() as u8,
+        );
+
+        style.foreground = Some(lit);
+        if style.background.is_none() && style.attributes.is_empty() {
+            Modifier::Colour(lit)
+        } else {
+            Modifier::Style(style)
+        }
+    }
+}
+
+impl Add for Illumination {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            intensity: self.intensity + rhs.intensity,
+            colour: self.colour + rhs.colour,
+            specular: self.specular + rhs.specular,
+        }
+    }
+}
+
+impl Mul<f64> for Illumination {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self {
+            intensity: self.intensity * rhs,
+            colour: self.colour * rhs,
+            specular: self.specular * rhs,
+        }
+    }
+}
+
+impl Sum for Illumination {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+/// Return the character from [`BRIGHTNESS_CHARS`] for a light intensity, where `0.0` is the darkest and `1.0` is the brightest
+fn brightness_char(intensity: f64) -> char {
+    let brightness_chars = BRIGHTNESS_CHARS.as_bytes();
+    let index = (intensity * brightness_chars.len() as f64).round() as usize;
+    char::from(brightness_chars[index.min(brightness_chars.len() - 1)])
+}
+
+/// Return the colour's channels scaled to between `0.0` and `1.0`
+fn colour_to_vec(colour: Colour) -> Vec3D {
+    Vec3D::new(
+        f64::from(colour.r),
+        f64::from(colour.g),
+        f64::from(colour.b),
+    ) / 255.0
+}
@@ -74,19 +74,23 @@
 
 use crate::{
     core::{CanDraw, Vec2D},
-    mesh3d::{Mesh3D, Transform3D},
-    primitives::{Line, Polygon},
+    mesh3d::{Material, Mesh3D, Transform3D, Vec3D},
+    primitives::Line,
 };
-use glam::DVec2;
+use glam::{DMat3, DVec2};
 
 mod display_mode;
+mod illumination;
 mod projected_face;
+mod rasterise;
 
 pub use display_mode::{
-    DisplayMode,
-    lighting::{BRIGHTNESS_CHARS, Light, LightType},
+    DisplayMode, LightColouring, Shading,
+    lighting::{Attenuation, BRIGHTNESS_CHARS, Light, LightType},
 };
+use illumination::Illumination;
 use projected_face::{ProjectedFace, ProjectedVertex};
+use std::borrow::Cow;
 
 /// The `Viewport` handles drawing 3D objects to a 2D [`Canvas`](crate::core::Canvas), and also acts as the scene's camera.
 pub struct Viewport {
@@ -104,6 +108,12 @@ pub struct Viewport {
     pub character_width_multiplier: f64,
     /// Any face with vertices closer to the viewport than this value will be clipped
     pub clipping_distace: f64,
+    /// If true, faces in [`DisplayMode::Solid`] and [`DisplayMode::Illuminated`] are split into triangles and drawn with [`Canvas::plot_with_depth`](crate::core::Canvas::plot_with_depth), passing each pixel's interpolated distance from the camera. When drawn to a canvas with a depth buffer, such as a [`View`](crate::view::View) created with [`with_depth_buffer`](crate::view::View::with_depth_buffer), this makes occlusion correct for every pixel, even where faces intersect or are very long. Faces must be convex. Defaults to `false`, in which case faces are only sorted by the distance of their centres from the camera
+    pub depth_testing: bool,
+    /// How light varies across each face in [`DisplayMode::Illuminated`]. [`Shading::Gouraud`] and [`Shading::PerPixel`] use the meshes' vertex normals, from [`Mesh3D::normals`] or calculated with [`Mesh3D::calculate_normals`], and always split faces into triangles as with `depth_testing`. Defaults to [`Shading::Flat`]
+    pub shading: Shading,
+    /// How light changes the appearance of faces in [`DisplayMode::Illuminated`], by replacing their characters, tinting their colours or both. Defaults to [`LightColouring::Characters`]
+    pub light_colouring: LightColouring,
 }
 
 impl Viewport {
@@ -118,11 +128,76 @@ impl Viewport {
             display_mode: DisplayMode::Solid,
             character_width_multiplier: 2.0,
             clipping_distace: 0.3,
+            depth_testing: false,
+            shading: Shading::Flat,
+            light_colouring: LightColouring::Characters,
         }
     }
 
-    /// Transform the vertices with the object transform, view transform and perspective transform
-    fn get_vertices_on_screen(&self, object: &Mesh3D) -> Vec<ProjectedVertex> {
+    /// Return the `Viewport` with `depth_testing` enabled. Consumes the original `Viewport`
+    #[must_use]
+    pub const fn with_depth_testing(mut self) -> Self {
+        self.depth_testing = true;
+        self
+    }
+
+    /// Return the `Viewport` with an updated `shading` property. Consumes the original `Viewport`
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::{mesh3d::{Mesh3D, Transform3D, Vec3D}, view3d::{DisplayMode, Light, Shading, Viewport}, view::View, core::ColChar};
+    /// let mut view = View::new(60, 30, ColChar::EMPTY);
+    /// let mut viewport = Viewport::new(
+    ///     Transform3D::look_at_lh(Vec3D::new(0.0, -3.0, 6.0), Vec3D::ZERO, Vec3D::Y),
+    ///     80.0,
+    ///     view.center(),
+    /// )
+    /// .with_shading(Shading::Gouraud);
+    /// viewport.objects.push(Mesh3D::torus(1.8, 0.8, 24, 12));
+    /// viewport.display_mode = DisplayMode::Illuminated {
+    ///     lights: vec![
+    ///         Light::new_ambient(0.2),
+    ///         Light::new_directional(0.8, Vec3D::new(1.0, 1.0, 1.0)),
+    ///     ],
+    /// };
+    ///
+    /// view.draw(&viewport);
+    /// ```
+    #[must_use]
+    pub const fn with_shading(mut self, shading: Shading) -> Self {
+        self.shading = shading;
+        self
+    }
+
+    /// Return the `Viewport` with an updated `light_colouring` property. Consumes the original `Viewport`
+    ///
+    /// ## Example
+    /// ```
+    /// # use gemini_engine::{mesh3d::{Mesh3D, Transform3D, Vec3D}, view3d::{DisplayMode, Light, LightColouring, Viewport}, view::View, core::{ColChar, Colour, Modifier, Vec2D}};
+    /// let mut view = View::new(40, 20, ColChar::EMPTY);
+    /// let mut viewport = Viewport::new(
+    ///     Transform3D::look_at_lh(Vec3D::new(0.0, -1.5, 4.3), Vec3D::ZERO, Vec3D::Y),
+    ///     80.0,
+    ///     view.center(),
+    /// )
+    /// .with_light_colouring(LightColouring::Colour);
+    /// viewport.objects.push(Mesh3D::default_cube());
+    /// viewport.display_mode = DisplayMode::Illuminated {
+    ///     lights: vec![Light::new_ambient(1.0).with_colour(Colour::rgb(255, 0, 0))],
+    /// };
+    ///
+    /// view.draw(&viewport);
+    /// // The cube's white faces are lit red, and their characters are unchanged
+    /// assert_eq!(view.get(view.center()), Some(ColChar::SOLID.with_mod(Modifier::from_rgb(255, 0, 0))));
+    /// ```
+    #[must_use]
+    pub const fn with_light_colouring(mut self, light_colouring: LightColouring) -> Self {
+        self.light_colouring = light_colouring;
+        self
+    }
+
+    /// Transform the vertices with the object transform, view transform and perspective transform. If `with_normals` is set, the vertex normals are transformed into camera space too
+    fn get_vertices_on_screen(&self, object: &Mesh3D, with_normals: bool) -> Vec<ProjectedVertex> {
         let world_transform = self.camera_transform.mul_mat4(&object.transform);
         let perspective =
             Transform3D::perspective_infinite_rh(self.fov.to_radians(), 1.0, self.clipping_distace);
@@ -130,40 +205,75 @@ impl Viewport {
         let centre = DVec2::new(self.canvas_centre.x as f64, self.canvas_centre.y as f64);
         let size = DVec2::splat(centre.max_element());
 
+        let normals = with_normals.then(|| {
+            object
+                .normals
+                .as_deref()
+                .map_or_else(|| Cow::Owned(object.calculate_normals()), Cow::Borrowed)
+        });
+        // Face normals are calculated from the transformed vertices and point into the mesh, so vertex normals are transformed with the cofactor matrix and flipped to match them
+        let linear = DMat3::from_mat4(world_transform);
+        let normal_transform = linear.inverse().transpose() * -linear.determinant();
+
         object
             .vertices
             .iter()
-            .map(|v| {
+            .enumerate()
+            .map(|(i, v)| {
                 let v = world_transform.transform_point3(*v); // Object and camera transform
                 let pv = perspective.project_point3(v); // Perspective
                 let pv = DVec2::new(pv.x * self.character_width_multiplier, -pv.y) * size + centre;
-                ProjectedVertex::new(v, Vec2D::new(pv.x as i64, pv.y as i64))
+                let normal = normals.as_ref().map(|normals| {
+                    let normal = normals.get(i).copied().unwrap_or_default();
+                    (normal_transform * normal).normalize_or_zero()
+                });
+                ProjectedVertex::new(v, Vec2D::new(pv.x as i64, pv.y as i64), normal)
             })
             .collect()
     }
 
     /// Project the models' faces onto a 2D plane. Returns a collection of `ProjectedFace`s, each storing its projected vertices, normal and z index
-    fn project_faces(&self, sort_faces: bool, backface_culling: bool) -> Vec<ProjectedFace> {
+    fn project_faces(
+        &self,
+        sort_faces: bool,
+        backface_culling: bool,
+        with_normals: bool,
+    ) -> Vec<ProjectedFace<'_>> {
         let mut screen_faces = vec![];
 
         for object in &self.objects {
-            let vertices = self.get_vertices_on_screen(object);
+            let vertices = self.get_vertices_on_screen(object, with_normals);
             for face in &object.faces {
                 let face_vertices = face
                     .index_into(&vertices)
                     .expect("Failed to index mesh vertices with face indices");
 
-                for v in &face_vertices {
-                    if v.original.z <= self.clipping_distace {
-                        continue; // Do not render if behind player
-                    }
+                if face_vertices
+                    .iter()
+                    .any(|v| v.original.z <= self.clipping_distace)
+                {
+                    continue; // Do not render if behind player
                 }
 
                 if backface_culling && !projected_face::is_clockwise(&face_vertices) {
                     continue; // Backface culling
                 }
 
-                screen_faces.push(ProjectedFace::new(face_vertices, face.fill_char));
+                let texture = object.texture.as_ref().zip(face.uvs.clone());
+                if let Some((_, uvs)) = &texture {
+                    assert_eq!(
+                        uvs.len(),
+                        face.v_indices.len(),
+                        "Mesh face has a different number of uvs than vertex indices"
+                    );
+                }
+
+                screen_faces.push(ProjectedFace::new(
+                    face_vertices,
+                    face.fill_char,
+                    texture,
+                    face.material.unwrap_or(object.material),
+                ));
             }
         }
 
@@ -181,7 +291,7 @@ impl CanDraw for Viewport {
     fn draw_to(&self, canvas: &mut impl crate::core::Canvas) {
         match &self.display_mode {
             DisplayMode::Wireframe { backface_culling } => {
-                let screen_faces = self.project_faces(false, *backface_culling);
+                let screen_faces = self.project_faces(false, *backface_culling, false);
 
                 for face in screen_faces {
                     for fi in 0..face.vertices.len() {
@@ -195,35 +305,54 @@ impl CanDraw for Viewport {
                 }
             }
             DisplayMode::Solid => {
-                let screen_faces = self.project_faces(true, true);
+                let screen_faces = self.project_faces(true, true, false);
 
                 for face in screen_faces {
-                    Polygon::new(&face.vertices, face.fill_char).draw_to(canvas);
+                    face.fill(canvas, self.depth_testing, |c| c);
                 }
             }
             DisplayMode::Illuminated { lights } => {
-                let screen_faces = self.project_faces(true, true);
-
-                let brightness_chars: Vec<char> = BRIGHTNESS_CHARS.chars().collect();
-                let len_brightness_chars: f64 = brightness_chars.len() as f64;
+                let screen_faces = self.project_faces(true, true, self.shading != Shading::Flat);
+                let colouring = self.light_colouring;
+                // Vertices are lit after `camera_transform` has been applied to them, which moves the camera to the origin
+                let camera_position = Vec3D::ZERO;
+                let illuminate = |point: Vec3D, normal: Vec3D, material: Material| {
+                    Illumination::from_lights(lights, point, normal, camera_position, material)
+                };
 
                 for face in screen_faces {
                     let Some(normal) = face.normal else {
                         continue;
                     };
 
-                    let intensity: f64 = lights
-                        .iter()
-                        .map(|light| light.calculate_intensity(face.original_centre, normal))
-                        .sum();
-
-                    let brightness_char_index = ((intensity * len_brightness_chars).round()
-                        as usize)
-                        .clamp(0, brightness_chars.len() - 1);
-                    let intensity_char = brightness_chars[brightness_char_index];
+                    match (self.shading, &face.vertex_normals) {
+                        (Shading::Gouraud, Some(normals)) => {
+                            let illuminations: Vec<Illumination> = face
+                                .original_vertices
+                                .iter()
+                                .zip(normals)
+                                .map(|(&point, &normal)| illuminate(point, normal, face.material))
+                                .collect();
 
-                    Polygon::new(&face.vertices, face.fill_char.with_char(intensity_char))
-                        .draw_to(canvas);
+                            face.fill_interpolated(canvas, self.depth_testing, |c, barycentric| {
+                                barycentric.interpolate(&illuminations).apply(c, colouring)
+                            });
+                        }
+                        (Shading::PerPixel, Some(normals)) => {
+                            face.fill_interpolated(canvas, self.depth_testing, |c, barycentric| {
+                                let point = barycentric.interpolate(&face.original_vertices);
+                                let normal = barycentric.interpolate(normals);
+                                illuminate(point, normal, face.material).apply(c, colouring)
+                            });
+                        }
+                        _ => {
+                            let illumination =
+                                illuminate(face.original_centre, normal, face.material);
+                            face.fill(canvas, self.depth_testing, |c| {
+                                illumination.apply(c, colouring)
+                            });
+                        }
+                    }
                 }
             }
         }
@@ -1,7 +1,10 @@
+use super::rasterise;
 use crate::{
-    core::{ColChar, Vec2D},
-    mesh3d::Vec3D,
+    core::{CanDraw, Canvas, ColChar, Vec2D},
+    mesh3d::{Material, TexCoord, Texture, Vec3D},
+    primitives::Polygon,
 };
+use std::{iter::Sum, ops::Mul};
 
 /// Returns true if the [`ProjectedVertex`]s in the slice are arranged clockwise
 #[must_use]
@@ -25,44 +28,139 @@ pub fn is_clockwise(points: &[ProjectedVertex]) -> bool {
 pub struct ProjectedVertex {
     pub original: Vec3D,
     pub projected: Vec2D,
+    /// The vertex normal in camera space, if it is needed for shading
+    pub normal: Option<Vec3D>,
 }
 
 impl ProjectedVertex {
-    pub const fn new(original: Vec3D, projected: Vec2D) -> Self {
+    pub const fn new(original: Vec3D, projected: Vec2D, normal: Option<Vec3D>) -> Self {
         Self {
             original,
             projected,
+            normal,
         }
     }
 }
 
-pub struct ProjectedFace {
+/// The perspective-correct barycentric weights of a pixel within one of the triangles that [`ProjectedFace::fill_interpolated`] splits a face into, used to interpolate values stored at the face's vertices
+#[derive(Debug, Clone, Copy)]
+pub struct Barycentric {
+    corners: [usize; 3],
+    weights: [f64; 3],
+}
+
+impl Barycentric {
+    /// Interpolate between the values at the corners of the triangle. `values` holds a value for every vertex of the face
+    pub fn interpolate<T: Copy + Mul<f64, Output = T> + Sum>(&self, values: &[T]) -> T {
+        self.corners
+            .iter()
+            .zip(self.weights)
+            .map(|(&c, w)| values[c] * w)
+            .sum()
+    }
+}
+
+pub struct ProjectedFace<'a> {
     /// The face's points, both in 3D and projected 2D space
     pub vertices: Vec<Vec2D>,
+    /// The position of each of the face's points relative to the camera
+    pub original_vertices: Vec<Vec3D>,
+    /// The distance of each of the face's points from the camera, along the camera's Z axis
+    pub depths: Vec<f64>,
     /// The normal vector of the face
     pub normal: Option<Vec3D>,
+    /// The normal vector of each of the face's points, if they are needed for shading
+    pub vertex_normals: Option<Vec<Vec3D>>,
     /// The distance from the camera
     pub original_centre: Vec3D,
     /// The face's fill [`ColChar`]
     pub fill_char: ColChar,
+    /// The texture mapped across the face and the texture coordinates of each of its vertices, if it has them
+    pub texture: Option<(&'a Texture, Vec<TexCoord>)>,
+    /// How the face responds to light
+    pub material: Material,
 }
 
-impl ProjectedFace {
-    pub fn new(vertices: Vec<ProjectedVertex>, fill_char: ColChar) -> Self {
+impl<'a> ProjectedFace<'a> {
+    pub fn new(
+        vertices: Vec<ProjectedVertex>,
+        fill_char: ColChar,
+        texture: Option<(&'a Texture, Vec<TexCoord>)>,
+        material: Material,
+    ) -> Self {
         let len = vertices.len();
+        let vertex_normals = vertices.iter().map(|v| v.normal).collect();
         let (original_vertices, vertices): (Vec<_>, Vec<_>) = vertices
             .into_iter()
             .map(|v| (v.original, v.projected))
             .unzip();
         Self {
+            depths: original_vertices.iter().map(|v| v.z).collect(),
             vertices,
             normal: (len >= 3).then(|| {
                 let v0 = original_vertices[0] - original_vertices[2];
                 let v1 = original_vertices[1] - original_vertices[2];
                 v0.cross(v1).normalize()
             }),
-            original_centre: original_vertices.into_iter().sum::<Vec3D>() / len as f64,
+            vertex_normals,
+            original_centre: original_vertices.iter().sum::<Vec3D>() / len as f64,
+            original_vertices,
             fill_char,
+            texture,
+            material,
+        }
+    }
+
+    /// Fill the face with its `fill_char`, or with its texture if it has one, passing each pixel through `shade` before plotting it. Untextured faces are drawn as a [`Polygon`] unless `depth_testing` is set, otherwise they are drawn as described in [`ProjectedFace::fill_interpolated`]
+    pub fn fill(
+        &self,
+        canvas: &mut impl Canvas,
+        depth_testing: bool,
+        shade: impl Fn(ColChar) -> ColChar,
+    ) {
+        if self.texture.is_none() && !depth_testing {
+            Polygon::new(&self.vertices, shade(self.fill_char)).draw_to(canvas);
+        } else {
+            self.fill_interpolated(canvas, depth_testing, |c, _| shade(c));
+        }
+    }
+
+    /// Split the face into a fan of triangles and plot every pixel covered by them, passing each pixel's [`Barycentric`] weights to `shade` so that it can interpolate values across the face. If `depth_testing` is set, pixels are plotted with their interpolated depth so that faces are correctly occluded on canvases with a depth buffer. Texture coordinates are interpolated with perspective correction, and [`ColChar::TRANSPARENT`] texels are skipped. Assumes the face is convex
+    pub fn fill_interpolated(
+        &self,
+        canvas: &mut impl Canvas,
+        depth_testing: bool,
+        shade: impl Fn(ColChar, Barycentric) -> ColChar,
+    ) {
+        for i in 1..self.vertices.len().saturating_sub(1) {
+            let corners = [0, i, i + 1];
+            let points = corners.map(|c| self.vertices[c]);
+            let depths = corners.map(|c| self.depths[c]);
+
+            rasterise::rasterise_triangle(points, |pos, weights| {
+                let barycentric = Barycentric {
+                    corners,
+                    weights: rasterise::perspective_weights(depths, weights),
+                };
+                let base_char = match &self.texture {
+                    Some((texture, uvs)) => {
+                        let texel = texture.sample(barycentric.interpolate(uvs));
+                        if texel.is_transparent() {
+                            return;
+                        }
+                        texel
+                    }
+                    None => self.fill_char,
+                };
+
+                let pixel = shade(base_char, barycentric);
+                if depth_testing {
+                    let depth = rasterise::interpolate_depth(depths, weights);
+                    canvas.plot_with_depth(pos, pixel, depth);
+                } else {
+                    canvas.plot(pos, pixel);
+                }
+            });
         }
     }
 }
@@ -0,0 +1,40 @@
+use crate::core::Vec2D;
+
+/// Call `plot` with the position of every pixel covered by the triangle, alongside the pixel's barycentric weights for each of the three corners. The weights add up to `1.0`, and can be used to interpolate values stored at the corners across the triangle. Degenerate triangles with no area are skipped
+pub fn rasterise_triangle(corners: [Vec2D; 3], mut plot: impl FnMut(Vec2D, [f64; 3])) {
+    let [a, b, c] = corners;
+    let area = (b - a).perp_dot(c - a);
+    if area == 0 {
+        return;
+    }
+
+    let (min, max) = (a.min(b).min(c), a.max(b).max(c));
+    for y in min.y..=max.y {
+        for x in min.x..=max.x {
+            let p = Vec2D::new(x, y);
+            let weights = [
+                (c - b).perp_dot(p - b),
+                (a - c).perp_dot(p - c),
+                (b - a).perp_dot(p - a),
+            ];
+
+            // The weights all share the sign of the area if the pixel is inside the triangle, regardless of winding order
+            if weights.iter().all(|w| w.signum() * area.signum() >= 0) {
+                plot(p, weights.map(|w| w as f64 / area as f64));
+            }
+        }
+    }
+}
+
+/// Interpolate a depth across a triangle using the barycentric weights from [`rasterise_triangle`]. Depth does not change linearly in screen space, so its reciprocal is interpolated instead to stay correct under perspective
+pub fn interpolate_depth(depths: [f64; 3], weights: [f64; 3]) -> f64 {
+    let inverse_depth: f64 = depths.iter().zip(weights).map(|(d, w)| w / d).sum();
+    inverse_depth.recip()
+}
+
+/// Correct the barycentric weights from [`rasterise_triangle`] for perspective, so that they can be used to interpolate values such as texture coordinates that change linearly across the triangle in 3D space rather than on the screen
+pub fn perspective_weights(depths: [f64; 3], weights: [f64; 3]) -> [f64; 3] {
+    let weights = [0, 1, 2].map(|i| weights[i] / depths[i]);
+    let total: f64 = weights.iter().sum();
+    weights.map(|w| w / total)
+}